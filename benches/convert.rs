@@ -0,0 +1,50 @@
+//核心转换函数的基准测试，运行cargo bench查看各函数的吞吐量
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use number_conversion::convert::*;
+use number_conversion::encoding::*;
+use std::hint::black_box;
+
+fn radix(c: &mut Criterion) {
+    let mut group = c.benchmark_group("进制");
+    //文本输入按字节计吞吐量，数值输入按个数
+    let binary = "1111_0000_1010_0101_1111_0000_1010_0101_1111_0000_1010_0101_1111_0000_1010_0101";
+    group.throughput(Throughput::Bytes(binary.len() as u64));
+    group.bench_function("parse_radix 2进制", |b| b.iter(|| parse_radix(black_box(binary), 2)));
+    let literal = "0xDEAD_BEEFu32";
+    group.throughput(Throughput::Bytes(literal.len() as u64));
+    group.bench_function("detect_number_format", |b| b.iter(|| detect_number_format(black_box(literal))));
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("BaseConversions", |b| b.iter(|| BaseConversions::new(black_box(0xDEAD_BEEF))));
+    group.finish();
+}
+
+fn float(c: &mut Criterion) {
+    let mut group = c.benchmark_group("f32");
+    group.throughput(Throughput::Elements(1));
+    group.bench_function("f32_to_hex", |b| b.iter(|| f32_to_hex(black_box(1.5e-3))));
+    let hex = "40490fd0";
+    group.throughput(Throughput::Bytes(hex.len() as u64));
+    group.bench_function("hex_to_f32", |b| b.iter(|| hex_to_f32(black_box(hex))));
+    group.finish();
+}
+
+//大输入走分配较多的路径
+fn large(c: &mut Criterion) {
+    let mut group = c.benchmark_group("大输入");
+    for len in [64, 4096, 10_000] {
+        let bytes: Vec<u8> = (0..len).map(|i| i as u8).collect();
+        let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        let digits = "1".repeat(len);
+        group.throughput(Throughput::Bytes(len as u64));
+        group.bench_with_input(BenchmarkId::new("parse_hex_bytes", len), &hex, |b, hex| b.iter(|| parse_hex_bytes(hex)));
+        group.bench_with_input(BenchmarkId::new("hex_dump", len), &bytes, |b, bytes| b.iter(|| hex_dump(bytes)));
+        group.bench_with_input(BenchmarkId::new("base58_encode", len), &bytes, |b, bytes| b.iter(|| base58_encode(bytes)));
+        group.bench_with_input(BenchmarkId::new("separate_digits", len), &digits, |b, digits| b.iter(|| separate_digits(digits, 4, '_')));
+        group.bench_with_input(BenchmarkId::new("BaseTable", len), &digits, |b, digits| b.iter(|| BaseTable::new(digits, 2)));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, radix, float, large);
+criterion_main!(benches);
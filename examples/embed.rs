@@ -0,0 +1,19 @@
+//在自己的egui程序中嵌入转换框，运行cargo run --example embed查看
+
+use eframe::egui;
+use number_conversion::converter::{PunycodeEncode, QpDecode};
+use number_conversion::widget::ConverterWidget;
+
+fn main() -> Result<(), eframe::Error> {
+    //内置转换器和自己实现的Converter都可以放进转换框
+    let mut punycode = ConverterWidget::new(Box::new(PunycodeEncode));
+    punycode.input = "münchen.de".to_string();
+    let mut quoted_printable = ConverterWidget::new(Box::new(QpDecode));
+    eframe::run_simple_native("嵌入转换框", Default::default(), move |ctx, _frame| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.add(&mut punycode);
+            ui.separator();
+            ui.add(&mut quoted_printable);
+        });
+    })
+}
@@ -0,0 +1,472 @@
+//! 完整的进制转换程序，需要gui特性；可在内置转换器之外注册新的转换器后启动
+
+use crate::base2::*;
+use crate::base8::*;
+use crate::base10::*;
+use crate::base16::*;
+use crate::base32_f32::*;
+use crate::basef32_32::*;
+use crate::changelog::*;
+use crate::char_lookup::*;
+use crate::compare_bases::*;
+use crate::converter_panel::*;
+use crate::data::*;
+#[cfg(feature = "compression")]
+use crate::decompress::*;
+use crate::f32_fields::*;
+use crate::hex_floats::*;
+use crate::i18n::*;
+use crate::inspector::*;
+use crate::keypad::*;
+use crate::multi_interpret::*;
+use crate::settings::*;
+use crate::ticks::*;
+use crate::timestamps::*;
+use crate::unit_convert::*;
+use crate::universal::*;
+use crate::widgets::*;
+use eframe::egui;
+use egui::*;
+use egui_extras::*;
+use crate::converter::Registry;
+
+/// 打开进制转换窗口，registry决定显示哪些通用转换器，branding决定仓库链接和配色
+///
+/// 可以先在内置转换器之外注册自己的转换器：
+///
+/// ```no_run
+/// use number_conversion::app::{run, Branding};
+/// use number_conversion::converter::{ConvertError, Converter, Registry};
+/// struct Reverse;
+/// impl Converter for Reverse {
+///     fn name(&self) -> &'static str { "反转" }
+///     fn hint(&self) -> &'static str { "把输入倒序" }
+///     fn output_label(&self) -> &'static str { "结果:" }
+///     fn convert(&self, input: &str) -> Result<String, ConvertError> {
+///         Ok(input.chars().rev().collect())
+///     }
+/// }
+/// let mut registry = Registry::with_builtins();
+/// registry.register(Box::new(Reverse));
+/// run(registry, Branding::default()).unwrap();
+/// ```
+pub fn run(registry: Registry, branding: Branding) -> Result<(), eframe::Error> {
+    let options = eframe::NativeOptions {
+        viewport: egui::ViewportBuilder::default().with_inner_size([800.0, 500.0]),
+        ..Default::default()
+    };
+    eframe::run_native("进制转换", options, Box::new(|cc| Box::new(App::new(cc, registry, branding))))
+}
+
+/// 定制发行版的外观：底部栏的仓库链接和界面配色，默认链接到上游仓库并使用egui的默认配色
+///
+/// 链接文字为中文时界面以它为键查找译文
+///
+/// ```
+/// use number_conversion::app::Branding;
+/// let branding = Branding::default().with_link("😄 Fork", "https://example.com/fork");
+/// assert_eq!(branding.link(), Some(("😄 Fork", "https://example.com/fork")));
+/// assert_eq!(Branding::default().without_link().link(), None);
+///
+/// // 配色在启动时应用一次
+/// let branding = Branding::default().with_visuals(eframe::egui::Visuals::light());
+/// assert!(branding.visuals().is_some_and(|visuals| !visuals.dark_mode));
+/// let ctx = eframe::egui::Context::default();
+/// branding.apply(&ctx);
+/// assert!(!ctx.style().visuals.dark_mode);
+/// ```
+pub struct Branding {
+    //(链接文字, 地址)，设为None时不显示链接
+    link: Option<(&'static str, &'static str)>,
+    //只在启动时应用一次，之后不会被覆盖；None时使用egui的默认配色
+    visuals: Option<egui::Visuals>,
+}
+
+impl Default for Branding {
+    fn default() -> Self {
+        Branding { link: Some(("😄 源码仓库", "https://github.com/AnlangA/number-conversion-rs")), visuals: None }
+    }
+}
+
+impl Branding {
+    /// 底部栏显示的链接文字和地址
+    pub fn with_link(mut self, label: &'static str, url: &'static str) -> Self {
+        self.link = Some((label, url));
+        self
+    }
+    /// 不显示仓库链接
+    pub fn without_link(mut self) -> Self {
+        self.link = None;
+        self
+    }
+    /// 界面配色，启动时应用一次，之后各帧不会覆盖
+    pub fn with_visuals(mut self, visuals: egui::Visuals) -> Self {
+        self.visuals = Some(visuals);
+        self
+    }
+    pub fn link(&self) -> Option<(&'static str, &'static str)> {
+        self.link
+    }
+    pub fn visuals(&self) -> Option<&egui::Visuals> {
+        self.visuals.as_ref()
+    }
+    /// 把配色应用到egui上下文，没有设置配色时保持原样
+    pub fn apply(&self, ctx: &egui::Context) {
+        if let Some(visuals) = &self.visuals {
+            ctx.set_visuals(visuals.clone());
+        }
+    }
+}
+
+struct App {
+    universal: Data,
+    compare_bases: Data,
+    base2: Data,
+    base8: Data,
+    base10: Data,
+    base16: Data,
+    base32_f32: Data,
+    f32_fields: Data,
+    multi_interpret: Data,
+    hex_floats: Data,
+    inspector: Data,
+    basef32_32: Data,
+    char_lookup: Data,
+    unit_convert: Data,
+    ticks: Data,
+    timestamps: Data,
+    #[cfg(feature = "compression")]
+    decompress_hex: Data,
+    settings: Settings,
+    //UTF-16、Quoted-Printable、Punycode、Base58等通用转换器，以及按注册顺序排列的各转换器数据
+    registry: Registry,
+    converter_data: Vec<(&'static str, Data)>,
+    keypad_target: Option<&'static str>,
+    branding: Branding,
+    show_changelog: bool,
+}
+
+impl App {
+    //registry决定显示哪些通用转换器，可在内置转换器之外注册新的转换器；branding决定仓库链接和配色
+    fn new(cc: &eframe::CreationContext<'_>, registry: Registry, branding: Branding) -> Self {
+        setup_custom_fonts(&cc.egui_ctx);
+        install_image_loaders(&cc.egui_ctx);
+        branding.apply(&cc.egui_ctx);
+        let (settings, show_changelog) = load_settings(cc.storage);
+        seed_random(settings.random_seed);
+        Self {
+            universal: Data::new(),
+            compare_bases: Data::new(),
+            base2: Data::new(),
+            base8: Data::new(),
+            base10: Data::new(),
+            base16: Data::new(),
+            base32_f32: Data::new(),
+            f32_fields: Data::new(),
+            multi_interpret: Data::new(),
+            hex_floats: Data::new(),
+            inspector: Data::new(),
+            basef32_32: Data::new(),
+            char_lookup: Data::new(),
+            unit_convert: Data::new(),
+            ticks: Data::new(),
+            timestamps: Data::new(),
+            #[cfg(feature = "compression")]
+            decompress_hex: Data::new(),
+            settings,
+            converter_data: registry.names().map(|name| (name, Data::new())).collect(),
+            registry,
+            keypad_target: None,
+            branding,
+            show_changelog,
+        }
+    }
+    fn universal(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.universal, &self.settings, ui, universal);
+    }
+    fn compare_bases(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.compare_bases, &self.settings, ui, compare_bases);
+    }
+    fn base2(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.base2, &self.settings, ui, base2);
+    }
+    fn base8(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.base8, &self.settings, ui, base8);
+    }
+    fn base10(&mut self, ui: &mut Ui){
+        show_panel(&mut self.base10, &self.settings, ui, base10);
+        octal_hint(&self.base10, &mut self.base8, ui);
+    }
+    fn base16(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.base16, &self.settings, ui, base16);
+    }
+    fn base32_f32(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.base32_f32, &self.settings, ui, base32_f32);
+    }
+    fn basef32_32(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.basef32_32, &self.settings, ui, basef32_32);
+    }
+    fn f32_fields(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.f32_fields, &self.settings, ui, f32_fields);
+    }
+    fn multi_interpret(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.multi_interpret, &self.settings, ui, multi_interpret);
+    }
+    fn hex_floats(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.hex_floats, &self.settings, ui, hex_floats);
+    }
+    fn inspector(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.inspector, &self.settings, ui, inspector);
+    }
+    fn char_lookup(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.char_lookup, &self.settings, ui, char_lookup);
+    }
+    //注册表中的转换器按注册顺序显示，有反向转换器时在其后放交换按钮
+    fn converters(&mut self, ui: &mut Ui) {
+        for i in 0..self.converter_data.len() {
+            let (name, data) = &mut self.converter_data[i];
+            let Some(converter) = self.registry.get(name) else {
+                continue;
+            };
+            show_panel(data, &self.settings, ui, |data, settings, ui| converter_panel(converter, data, settings, ui));
+            let inverse = converter.inverse();
+            let inverse_index = self.converter_data.iter().position(|(name, _)| Some(*name) == inverse);
+            if let Some(j) = inverse_index.filter(|&j| j != i) {
+                let (from, to) = pair_mut(&mut self.converter_data, i, j);
+                swap_button(&from.1, &mut to.1, ui);
+            }
+        }
+    }
+    fn unit_convert(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.unit_convert, &self.settings, ui, unit_convert);
+    }
+    fn ticks(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.ticks, &self.settings, ui, ticks);
+    }
+    fn timestamps(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.timestamps, &self.settings, ui, timestamps);
+    }
+    #[cfg(feature = "compression")]
+    fn decompress_hex(&mut self, ui: &mut Ui) {
+        show_panel(&mut self.decompress_hex, &self.settings, ui, decompress_hex);
+    }
+    //返回是否恢复了默认设置
+    fn settings(&mut self, ui: &mut Ui) -> bool {
+        settings(&mut self.settings, ui)
+    }
+    fn github_link(&mut self, ctx: &egui::Context){
+        egui::TopBottomPanel::bottom("链接")
+            .show(ctx, |ui|{
+                ui.horizontal(|ui| {
+                    if let Some((label, url)) = self.branding.link {
+                        ui.add(egui::Hyperlink::from_label_and_url(t(label), url));
+                        ui.separator();
+                    }
+                    clipboard_menu(ui);
+                    ui.separator();
+                    changelog_button(&mut self.show_changelog, ui);
+                    ui.separator();
+                    self.status_bar(ui);
+                });
+            });
+    }
+    //各输入框的名称、屏幕键盘可输入数字的进制(None表示不限制)和数据
+    fn fields_mut(&mut self) -> Vec<(&'static str, Option<u32>, &mut Data)> {
+        let mut fields = vec![
+            ("智能输入", None, &mut self.universal),
+            ("进制对照表", None, &mut self.compare_bases),
+            ("2进制数", Some(2), &mut self.base2),
+            ("8进制数", Some(8), &mut self.base8),
+            ("10进制数", Some(10), &mut self.base10),
+            ("16进制数", Some(16), &mut self.base16),
+            ("f32数据", None, &mut self.basef32_32),
+            ("f32的16进制数编码", Some(16), &mut self.base32_f32),
+            ("多重解释", Some(16), &mut self.multi_interpret),
+            ("16进制转浮点数组", Some(16), &mut self.hex_floats),
+            ("字节检查器", Some(16), &mut self.inspector),
+            ("字符查询", None, &mut self.char_lookup),
+            ("单位换算", None, &mut self.unit_convert),
+            ("时长与节拍换算", None, &mut self.ticks),
+            ("时间戳解析", None, &mut self.timestamps),
+        ];
+        let registry = &self.registry;
+        fields.extend(self.converter_data.iter_mut().map(|(name, data)| (*name, registry.get(name).and_then(|c| c.input_radix()), data)));
+        #[cfg(feature = "compression")]
+        fields.push(("解压", Some(16), &mut self.decompress_hex));
+        fields
+    }
+    //点击按键会让输入框失去焦点，所以键盘作用于最近一次获得焦点的输入框
+    fn keypad(&mut self, ctx: &egui::Context) {
+        let focused = self.fields_mut().into_iter().find(|(_, _, data)| data.has_focus).map(|(name, _, _)| name);
+        if focused.is_some() {
+            self.keypad_target = focused;
+        }
+        if !self.settings.show_keypad {
+            return;
+        }
+        let target_name = self.keypad_target;
+        egui::SidePanel::right("键盘").show(ctx, |ui| {
+            let target = self.fields_mut().into_iter().find(|(name, _, _)| Some(*name) == target_name);
+            keypad(target, ui);
+        });
+    }
+    //显示当前输入框的长度和格式状态
+    fn status_bar(&mut self, ui: &mut Ui) {
+        let fields = self.fields_mut();
+        match fields.iter().find(|(_, _, data)| data.has_focus) {
+            Some((name, _, data)) => {
+                ui.label(format!(
+                    "{}  {}:{}  {}:{}  {}",
+                    t(name),
+                    t("字符数"),
+                    data.input_data.chars().count(),
+                    t("字节数"),
+                    data.input_data.len(),
+                    data.get_data_error().localized(language())
+                ));
+            }
+            None => {
+                ui.label(t("未选中输入框"));
+            }
+        }
+    }
+}
+
+impl eframe::App for App {
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        eframe::set_value(storage, eframe::APP_KEY, &self.settings);
+    }
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        //界面文字在本帧内统一使用设置中的语言
+        set_language(self.settings.language);
+        //底部面板需要先于CentralPanel添加，否则会遮挡滚动区域的内容
+        self.github_link(ctx);
+        self.keypad(ctx);
+        changelog_window(&mut self.show_changelog, ctx);
+        let mut reset = false;
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ScrollArea::vertical().show(ui, |ui| {
+                if self.settings.compact {
+                    ui.spacing_mut().item_spacing.y = 1.0;
+                }
+                reset = self.settings(ui);
+                self.universal(ui);
+                self.compare_bases(ui);
+                self.base2(ui);
+                self.base8(ui);
+                self.base10(ui);
+                self.base16(ui);
+                self.basef32_32(ui);
+                swap_button(&self.basef32_32, &mut self.base32_f32, ui);
+                self.base32_f32(ui);
+                swap_button(&self.base32_f32, &mut self.basef32_32, ui);
+                self.f32_fields(ui);
+                self.multi_interpret(ui);
+                self.hex_floats(ui);
+                self.inspector(ui);
+                self.char_lookup(ui);
+                self.converters(ui);
+                self.unit_convert(ui);
+                self.ticks(ui);
+                self.timestamps(ui);
+                #[cfg(feature = "compression")]
+                self.decompress_hex(ui);
+                if !self.settings.compact {
+                    ui.vertical_centered(|ui| {
+                        ui.add(Image::new(include_image!("./picture/rust_zh.png")).max_height(150.0));
+                    });
+                }
+            });
+        });
+        //恢复默认后立即写入配置，不等eframe定时保存
+        if reset {
+            if let Some(storage) = frame.storage_mut() {
+                self.save(storage);
+                storage.flush();
+            }
+        }
+    }
+}
+
+//读取上次保存的设置，没有时按首次启动处理；升级后首次启动时打开更新说明，之后记为已看过
+fn load_settings(storage: Option<&dyn eframe::Storage>) -> (Settings, bool) {
+    //Settings::new与Default不同，会取时间种子
+    let mut settings = match storage.and_then(|storage| eframe::get_value::<Settings>(storage, eframe::APP_KEY)) {
+        Some(settings) => settings,
+        None => Settings::new(),
+    };
+    let show_changelog = should_show(settings.last_seen_version.as_deref(), VERSION);
+    settings.last_seen_version = Some(VERSION.to_string());
+    (settings, show_changelog)
+}
+
+//只在App::new中调用一次：set_fonts会让egui重建字体图集，不能放进每帧执行的update
+fn setup_custom_fonts(ctx: &egui::Context) {
+    let mut fonts = egui::FontDefinitions::default();
+
+    fonts.font_data.insert(
+        "Song".to_owned(),
+        egui::FontData::from_static(include_bytes!("./STSong.ttf")),
+    );
+
+    fonts
+        .families
+        .entry(egui::FontFamily::Proportional)
+        .or_default()
+        .insert(0, "Song".to_owned());
+
+    fonts
+        .families
+        .entry(egui::FontFamily::Monospace)
+        .or_default()
+        .push("Song".to_owned());
+
+    ctx.set_fonts(fonts);
+}
+
+//同时借用切片中两个不同的元素，前一个只读
+fn pair_mut<T>(items: &mut [T], i: usize, j: usize) -> (&T, &mut T) {
+    if i < j {
+        let (head, tail) = items.split_at_mut(j);
+        (&head[i], &mut tail[0])
+    } else {
+        let (head, tail) = items.split_at_mut(i);
+        (&tail[0], &mut head[j])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MemoryStorage(HashMap<String, String>);
+
+    impl eframe::Storage for MemoryStorage {
+        fn get_string(&self, key: &str) -> Option<String> {
+            self.0.get(key).cloned()
+        }
+        fn set_string(&mut self, key: &str, value: String) {
+            self.0.insert(key.to_string(), value);
+        }
+        fn flush(&mut self) {}
+    }
+
+    #[test]
+    fn stored_older_version_opens_changelog() {
+        let mut storage = MemoryStorage::default();
+        let stored = Settings { last_seen_version: Some("0.1.0".to_string()), hex_uppercase: true, ..Settings::default() };
+        eframe::set_value(&mut storage, eframe::APP_KEY, &stored);
+        let (settings, show_changelog) = load_settings(Some(&storage));
+        assert!(show_changelog);
+        assert!(settings.hex_uppercase);
+        assert_eq!(settings.last_seen_version.as_deref(), Some(VERSION));
+
+        //保存后再次启动不再打开
+        eframe::set_value(&mut storage, eframe::APP_KEY, &settings);
+        assert!(!load_settings(Some(&storage)).1);
+        //首次启动没有保存的设置，也不打开
+        assert!(!load_settings(None).1);
+    }
+}
@@ -1,28 +1,51 @@
+use crate::i18n::*;
 use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
 use eframe::egui;
 use egui::*;
-use num::BigUint;
+use crate::convert::*;
 
-pub fn base10(data: &mut Data, ui: &mut Ui) {
+pub fn base10(data: &mut Data, settings: &Settings, ui: &mut Ui) {
     data.set_data_error(DataError::Nice);
     let mut input_data = String::new();
+    let mut invalid = Vec::new();
+    let mut lost_focus = false;
+    let mut negative = false;
+    //是否同时显示大端序和小端序的16进制，存放在egui的临时数据中
+    let byte_order_id = ui.id().with("10进制数字节序");
+    let mut show_byte_orders = ui.data(|d| d.get_temp::<bool>(byte_order_id)).unwrap_or(false);
     ui.horizontal(|ui| {
-        ui.label(RichText::from("10进制数").color(Color32::BLUE)).on_hover_text("可输入下划线做视觉分割");
-        let text_edit = TextEdit::singleline(&mut data.input_data)
-        .desired_width(400.0);
-        ui.add(text_edit);
+        ui.label(RichText::from(t("10进制数")).color(Color32::BLUE)).on_hover_text(t("可输入下划线做视觉分割，负数按位宽显示补码"));
+        let response = radix_input_edit(data, settings, 10, ui);
+        lost_focus = response.lost_focus();
+        data.has_focus = response.has_focus();
+        example_menu(data, "10进制数", ui);
+        history_menu(data, ui);
+        //随机右移，让生成的数值长短不一
+        random_button(data, ui, |r| to_radix(r >> (r % 64), 10));
+        ui.checkbox(&mut show_byte_orders, t("字节序")).on_hover_text(t("同时显示大端序和小端序的16进制，宽度为设置中的位宽，自动时取整字节"));
 
-        //允许输入"_"做视觉区分
-        let raw_data = data.ref_input_data().clone().replace("_", "");
+        //允许输入"_"做视觉区分，忽略u8等类型后缀
+        let raw_data = strip_type_suffix(&data.ref_input_data().replace("_", "")).to_string();
+        //开头的负号单独记录，其余字符必须是数字
+        let raw_data = match raw_data.strip_prefix('-') {
+            Some(digits) => {
+                negative = true;
+                digits.to_string()
+            }
+            None => raw_data,
+        };
 
         if raw_data.is_empty() {
             data.set_data_error(DataError::LenNull);
         }
         
+        invalid = invalid_chars(&raw_data, 10);
         input_data = raw_data
             .chars()
             .filter(|c| {
-                if !c.is_digit(10) {
+                if !c.is_ascii_digit() {
                     data.set_data_error(DataError::FormatError);
                     false
                 } else {
@@ -31,9 +54,16 @@ pub fn base10(data: &mut Data, ui: &mut Ui) {
             })
             .collect();
     });
+    ui.data_mut(|d| d.insert_temp(byte_order_id, show_byte_orders));
     let mut number_data: u64 = 0;
-    match u64::from_str_radix(&input_data, 10){
-        Ok(data) => number_data = data,
+    let mut overflowed = false;
+    let parsed = if negative {
+        format!("-{}", input_data).parse::<i64>().map(|value| twos_complement(value, settings.width_bits()))
+    } else {
+        input_data.parse::<u64>().map(|value| settings.mask(value))
+    };
+    match parsed {
+        Ok((data, truncated)) => (number_data, overflowed) = (data, truncated),
         Err(_) => {
             if data.get_data_error() == &DataError::Nice {
                  data.set_data_error(DataError::LenOver);
@@ -42,20 +72,44 @@ pub fn base10(data: &mut Data, ui: &mut Ui) {
     };
     ui.horizontal(|ui| {
         match data.get_data_error() {
-            DataError::FormatError => ui.colored_label(Color32::RED, "请输入10进制字符"),
-            DataError::LenNull => ui.colored_label(Color32::RED, "请输入数值"),
-            DataError::LenOver => ui.colored_label(Color32::RED, format!("数值大于u64最大值:{}",u64::MAX)),
+            DataError::FormatError => format_error(ui, t("请输入10进制字符"), &invalid),
+            DataError::LenNull => ui.colored_label(Color32::RED, t("请输入数值")),
+            DataError::LenOver if negative => ui.colored_label(Color32::RED, t("数值超出i64范围")),
+            DataError::LenOver => ui.colored_label(Color32::RED, t("数值大于u64最大值:{}").replace("{}", &u64::MAX.to_string())),
             DataError::Nice => {
-                    let string_data = BigUint::from(number_data).to_str_radix(2);
-                    data.set_output_data(string_data);
-                    ui.add(Label::new(RichText::new("2进制数:").color(Color32::BLUE)));
-                    ui.monospace(data.get_output_data());
+                    let binary = settings.pad(to_radix(number_data, 2), 2);
+                    let hex = settings.hex_case(settings.pad(to_radix(number_data, 16), 16));
+                    ui.add(Label::new(RichText::new(t("2进制数:")).color(Color32::BLUE)));
+                    binary_output(ui, &binary, settings);
                     ui.separator();
-                    let string_data = BigUint::from(number_data).to_str_radix(16);
-                    data.set_output_data(string_data);
-                    ui.add(Label::new(RichText::new("16进制数:").color(Color32::BLUE)));
-                    ui.monospace(data.get_output_data())
+                    ui.add(Label::new(RichText::new(t("16进制数:")).color(Color32::BLUE)));
+                    let response = radix_copyable(ui, &settings.group(&hex, 16), 16, settings);
+                    data.set_output_data(radix_results(&[(2, &binary), (16, &hex)]));
+                    response
             }
         }
     });
+    if data.get_data_error() == &DataError::Nice {
+        overflow_note(overflowed, settings, ui);
+        if show_byte_orders {
+            //258按16位为大端序0102、小端序0201
+            let bits = settings.bit_width.unwrap_or(significant_bytes(number_data).max(1) * 8);
+            let (big_endian, little_endian) = byte_orders(number_data, bits);
+            ui.horizontal(|ui| {
+                ui.add(Label::new(RichText::new(t("大端序")).color(Color32::BLUE)));
+                radix_copyable(ui, &settings.hex_case(big_endian), 16, settings);
+                ui.separator();
+                ui.add(Label::new(RichText::new(t("小端序")).color(Color32::BLUE)));
+                radix_copyable(ui, &settings.hex_case(little_endian), 16, settings);
+            });
+        }
+        bit_stats(number_data, None, ui);
+        code_literals(number_data, settings, ui);
+        bit_weight_breakdown(number_data, ui);
+        byte_size(number_data, ui);
+    }
+    //失去焦点且转换成功时记录历史
+    if lost_focus && data.get_data_error() == &DataError::Nice {
+        data.push_history();
+    }
 }
@@ -1,30 +1,60 @@
+use crate::i18n::*;
 use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
 use eframe::egui;
 use egui::*;
-use num::BigUint;
+use crate::convert::*;
 
-pub fn base16(data: &mut Data, ui: &mut Ui) {
+pub fn base16(data: &mut Data, settings: &Settings, ui: &mut Ui) {
     data.set_data_error(DataError::Nice);
     let mut input_data = String::new();
+    let mut invalid = Vec::new();
+    let mut lost_focus = false;
+    //是否同时按补码显示有符号数，存放在egui的临时数据中
+    let signed_id = ui.id().with("16进制数有符号");
+    let mut signed = ui.data(|d| d.get_temp::<bool>(signed_id)).unwrap_or(false);
     ui.horizontal(|ui| {
-        ui.label(RichText::from("16进制数").color(Color32::BLUE)).on_hover_text("可输入下划线做视觉分割");
-        let text_edit = TextEdit::singleline(&mut data.input_data)
-        .desired_width(400.0);
-        ui.add(text_edit);
+        ui.label(RichText::from(t("16进制数")).color(Color32::BLUE)).on_hover_text(t("可输入下划线做视觉分割，可带0x前缀"));
+        let response = radix_input_edit(data, settings, 16, ui);
+        lost_focus = response.lost_focus();
+        data.has_focus = response.has_focus();
+        example_menu(data, "16进制数", ui);
+        history_menu(data, ui);
+        //随机右移，让生成的数值长短不一
+        random_button(data, ui, |r| to_radix(r >> (r % 64), 16));
+        ui.checkbox(&mut signed, t("有符号")).on_hover_text(t("按补码解释为有符号数，位宽为设置中的位宽，自动时为输入的位数"));
 
-        //允许输入"_"做视觉区分
-        let raw_data = data.ref_input_data().clone().replace("_", "");
+        //数据倒序存储时可直接反转
+        if ui.button(t("半字节反转")).clicked() {
+            let raw_data = hex_digits(data.ref_input_data());
+            *data.ref_input_data() = reverse_nibbles(&raw_data);
+        }
+        let raw_data = hex_digits(data.ref_input_data());
+        if ui
+            .add_enabled(raw_data.len().is_multiple_of(2), Button::new(t("字节反转")))
+            .on_disabled_hover_text(t("字节反转需要偶数个字符"))
+            .clicked()
+        {
+            if let Some(reversed) = reverse_bytes(&raw_data) {
+                *data.ref_input_data() = reversed;
+            }
+        }
+
+        let raw_data = hex_digits(data.ref_input_data());
 
         if raw_data.is_empty() {
             data.set_data_error(DataError::LenNull);
-        }else if raw_data.len() > 16 {
+        }else if raw_data.len() > settings.max_hex_len {
             data.set_data_error(DataError::LenOver);
         }
         
+        length_indicator(raw_data.len(), settings.max_hex_len, ui);
+        invalid = invalid_chars(&raw_data, 16);
         input_data = raw_data
             .chars()
             .filter(|c| {
-                if !c.is_digit(16) {
+                if !c.is_ascii_hexdigit() {
                     data.set_data_error(DataError::FormatError);
                     false
                 } else {
@@ -33,23 +63,59 @@ pub fn base16(data: &mut Data, ui: &mut Ui) {
             })
             .collect();
     });
+    ui.data_mut(|d| d.insert_temp(signed_id, signed));
+    group_preview(&input_data, settings.hex_group_size, ui);
+    //超出位宽的高位被截断
+    let (number_data, overflowed) = settings.mask(parse_radix(&input_data, 16).unwrap_or(0));
     ui.horizontal(|ui| {
         match data.get_data_error() {
-            DataError::FormatError => ui.colored_label(Color32::RED, "请输入16进制字符"),
-            DataError::LenNull => ui.colored_label(Color32::RED, "请输入数值"),
-            DataError::LenOver => ui.colored_label(Color32::RED, "数值长度超过16位"),
+            DataError::FormatError => format_error(ui, t("请输入16进制字符"), &invalid),
+            DataError::LenNull => ui.colored_label(Color32::RED, t("请输入数值")),
+            DataError::LenOver => ui.colored_label(Color32::RED, t("数值长度超过{}位").replace("{}", &settings.max_hex_len.to_string())),
             DataError::Nice => {
-                    let number_data = u64::from_str_radix(&input_data, 16).unwrap();
-                    let string_data = BigUint::from(number_data).to_str_radix(2);
-                    data.set_output_data(string_data);
-                    ui.add(Label::new(RichText::new("2进制数:").color(Color32::BLUE)));
-                    ui.monospace(data.get_output_data());
+                    let binary = settings.pad(to_radix(number_data, 2), 2);
+                    let decimal = to_radix(number_data, 10);
+                    ui.add(Label::new(RichText::new(t("2进制数:")).color(Color32::BLUE)));
+                    binary_output(ui, &binary, settings);
                     ui.separator();
-                    let string_data = BigUint::from(number_data).to_str_radix(10);
-                    data.set_output_data(string_data);
-                    ui.add(Label::new(RichText::new("10进制数:").color(Color32::BLUE)));
-                    ui.monospace(data.get_output_data())
+                    ui.add(Label::new(RichText::new(t("10进制数:")).color(Color32::BLUE)));
+                    let response = copyable(ui, &settings.group(&decimal, 10));
+                    if signed {
+                        //0xFFF6按16位解释为-10
+                        let bits = settings.bit_width.unwrap_or(input_data.len() as u32 * 4);
+                        ui.separator();
+                        ui.add(Label::new(RichText::new(t("有符号10进制数:")).color(Color32::BLUE)));
+                        copyable(ui, &settings.group(&sign_extend(number_data, bits).to_string(), 10));
+                    }
+                    data.set_output_data(radix_results(&[(2, &binary), (10, &decimal)]));
+                    response
             }
         }
     });
+    if data.get_data_error() == &DataError::Nice {
+        overflow_note(overflowed, settings, ui);
+        bit_stats(number_data, Some(input_data.len() * 4), ui);
+        code_literals(number_data, settings, ui);
+        byte_size(number_data, ui);
+    }
+    //失去焦点且转换成功时记录历史
+    if lost_focus && data.get_data_error() == &DataError::Nice {
+        data.push_history();
+    }
+}
+
+//允许输入"_"做视觉区分，忽略"0x"前缀和u8等类型后缀
+fn hex_digits(input: &str) -> String {
+    strip_radix_prefix(strip_type_suffix(&input.replace('_', "")), 16).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reverse_ignores_type_suffix() {
+        assert_eq!(reverse_nibbles(&hex_digits("FFu8")), "FF");
+        assert_eq!(reverse_bytes(&hex_digits("0xAB_CDu16")), Some("CDAB".to_string()));
+    }
 }
@@ -1,26 +1,37 @@
+use crate::i18n::*;
 use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
 use eframe::egui;
 use egui::*;
-use num::BigUint;
+use crate::convert::*;
 
-pub fn base2(data: &mut Data, ui: &mut Ui) {
+pub fn base2(data: &mut Data, settings: &Settings, ui: &mut Ui) {
     data.set_data_error(DataError::Nice);
     let mut input_data = String::new();
+    let mut invalid = Vec::new();
+    let mut lost_focus = false;
     ui.horizontal(|ui| {
-        ui.label(RichText::from("2进制数").color(Color32::BLUE)).on_hover_text("可输入下划线做视觉分割");
-        let text_edit = TextEdit::singleline(&mut data.input_data)
-        .desired_width(400.0);
-        ui.add(text_edit);
+        ui.label(RichText::from(t("2进制数")).color(Color32::BLUE)).on_hover_text(t("可输入下划线做视觉分割，可带0b前缀"));
+        let response = radix_input_edit(data, settings, 2, ui);
+        lost_focus = response.lost_focus();
+        data.has_focus = response.has_focus();
+        example_menu(data, "2进制数", ui);
+        history_menu(data, ui);
+        //随机右移，让生成的数值长短不一
+        random_button(data, ui, |r| to_radix(r >> (r % 64), 2));
 
-        //允许输入"_"做视觉区分
-        let raw_data = data.ref_input_data().clone().replace("_", "");
+        //允许输入"_"做视觉区分，忽略"0b"前缀和u8等类型后缀
+        let raw_data = strip_radix_prefix(strip_type_suffix(&data.ref_input_data().replace("_", "")), 2).to_string();
 
         if raw_data.is_empty() {
             data.set_data_error(DataError::LenNull);
-        }else if raw_data.len() > 64 {
+        }else if raw_data.len() > settings.max_binary_len {
             data.set_data_error(DataError::LenOver);
         }
         
+        length_indicator(raw_data.len(), settings.max_binary_len, ui);
+        invalid = invalid_chars(&raw_data, 2);
         input_data = raw_data
             .chars()
             .filter(|c| {
@@ -33,23 +44,34 @@ pub fn base2(data: &mut Data, ui: &mut Ui) {
             })
             .collect();
     });
+    group_preview(&input_data, settings.binary_group_size, ui);
+    //超出位宽的高位被截断
+    let (number_data, overflowed) = settings.mask(parse_radix(&input_data, 2).unwrap_or(0));
     ui.horizontal(|ui| {
         match data.get_data_error() {
-            DataError::FormatError => ui.colored_label(Color32::RED, "请输入2进制字符"),
-            DataError::LenNull => ui.colored_label(Color32::RED, "请输入数值"),
-            DataError::LenOver => ui.colored_label(Color32::RED, "数值长度超过64位"),
+            DataError::FormatError => format_error(ui, t("请输入2进制字符"), &invalid),
+            DataError::LenNull => ui.colored_label(Color32::RED, t("请输入数值")),
+            DataError::LenOver => ui.colored_label(Color32::RED, t("数值长度超过{}位").replace("{}", &settings.max_binary_len.to_string())),
             DataError::Nice => {
-                    let number_data = u64::from_str_radix(&input_data, 2).unwrap();
-                    let string_data = BigUint::from(number_data).to_str_radix(16);
-                    data.set_output_data(string_data);
-                    ui.add(Label::new(RichText::new("16进制数:").color(Color32::BLUE)));
-                    ui.monospace(data.get_output_data());
+                    let hex = settings.hex_case(settings.pad(to_radix(number_data, 16), 16));
+                    let decimal = to_radix(number_data, 10);
+                    ui.add(Label::new(RichText::new(t("16进制数:")).color(Color32::BLUE)));
+                    radix_copyable(ui, &settings.group(&hex, 16), 16, settings);
                     ui.separator();
-                    let string_data = BigUint::from(number_data).to_str_radix(10);
-                    data.set_output_data(string_data);
-                    ui.add(Label::new(RichText::new("10进制数:").color(Color32::BLUE)));
-                    ui.monospace(data.get_output_data())
+                    ui.add(Label::new(RichText::new(t("10进制数:")).color(Color32::BLUE)));
+                    let response = copyable(ui, &settings.group(&decimal, 10));
+                    data.set_output_data(radix_results(&[(16, &hex), (10, &decimal)]));
+                    response
             }
         }
     });
+    if data.get_data_error() == &DataError::Nice {
+        overflow_note(overflowed, settings, ui);
+        bit_stats(number_data, Some(input_data.len()), ui);
+        code_literals(number_data, settings, ui);
+    }
+    //失去焦点且转换成功时记录历史
+    if lost_focus && data.get_data_error() == &DataError::Nice {
+        data.push_history();
+    }
 }
@@ -1,19 +1,28 @@
+use crate::i18n::*;
 use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
 use eframe::egui;
 use egui::*;
+use crate::convert::*;
 
 
-pub fn base32_f32(data: &mut Data, ui: &mut Ui) {
+pub fn base32_f32(data: &mut Data, settings: &Settings, ui: &mut Ui) {
     data.set_data_error(DataError::Nice);
     let mut input_data = String::new();
+    let mut invalid = Vec::new();
+    //是否按字节反转后解释，存放在egui的临时数据中
+    let swap_id = ui.id().with("f32字节反转");
+    let mut swap = ui.data(|d| d.get_temp::<bool>(swap_id)).unwrap_or(false);
     ui.horizontal(|ui| {
-        ui.label(RichText::from("输入f32的16进制数编码").color(Color32::BLUE)).on_hover_text("可输入下划线做视觉分割");
-        let text_edit = TextEdit::singleline(&mut data.input_data)
-        .desired_width(400.0);
-        ui.add(text_edit);
+        ui.label(RichText::from(t("输入f32的16进制数编码")).color(Color32::BLUE)).on_hover_text(t("可输入下划线做视觉分割，可带0x前缀"));
+        data.has_focus = radix_input_edit(data, settings, 16, ui).has_focus();
+        example_menu(data, "f32的16进制数编码", ui);
+        random_button(data, ui, |r| format!("{:08x}", r as u32));
+        ui.checkbox(&mut swap, t("字节反转")).on_hover_text(t("按字节反转后再解释，用于小端序的数据"));
 
-        //允许输入"_"做视觉区分
-        let raw_data = data.ref_input_data().clone().replace("_", "");
+        //允许输入"_"做视觉区分，忽略"0x"前缀和u32等类型后缀
+        let raw_data = strip_radix_prefix(strip_type_suffix(&data.ref_input_data().replace("_", "")), 16).to_string();
 
         if raw_data.is_empty() {
             data.set_data_error(DataError::LenNull);
@@ -21,10 +30,12 @@ pub fn base32_f32(data: &mut Data, ui: &mut Ui) {
             data.set_data_error(DataError::LenOver);
         }
         
+        length_indicator(raw_data.len(), 8, ui);
+        invalid = invalid_chars(&raw_data, 16);
         input_data = raw_data
             .chars()
             .filter(|c| {
-                if !c.is_digit(16) {
+                if !c.is_ascii_hexdigit() {
                     data.set_data_error(DataError::FormatError);
                     false
                 } else {
@@ -33,18 +44,49 @@ pub fn base32_f32(data: &mut Data, ui: &mut Ui) {
             })
             .collect();
     });
+    ui.data_mut(|d| d.insert_temp(swap_id, swap));
     ui.horizontal(|ui| {
         match data.get_data_error() {
-            DataError::FormatError => ui.colored_label(Color32::RED, "请输入16进制字符"),
-            DataError::LenNull => ui.colored_label(Color32::RED, "请输入数值"),
-            DataError::LenOver => ui.colored_label(Color32::RED, "数值长度超过8位"),
+            DataError::FormatError => format_error(ui, t("请输入16进制字符"), &invalid),
+            DataError::LenNull => ui.colored_label(Color32::RED, t("请输入数值")),
+            DataError::LenOver => ui.colored_label(Color32::RED, t("数值长度超过{}位").replace("{}", "8")),
             DataError::Nice => {
-                    let number_data = u32::from_str_radix(&input_data, 16).unwrap();
-                    let string_data = f32::from_bits(number_data).to_string();
-                    data.set_output_data(string_data);
-                    ui.add(Label::new(RichText::new("f32浮点数").color(Color32::BLUE)));
-                    ui.monospace(data.get_output_data())
+                    //开启字节反转时先显示原始解释，再显示反转后的解释
+                    let swapped = swap.then(|| swap_float_bytes(&input_data, 32)).flatten();
+                    if swapped.is_some() {
+                        let original = hex_to_f32(&input_data).unwrap();
+                        ui.add(Label::new(RichText::new(t("原始:")).color(Color32::GRAY)));
+                        copyable(ui, settings.special_value_label(original as f64).unwrap_or(&settings.format_f32(original)));
+                        ui.separator();
+                    }
+                    let value = hex_to_f32(swapped.as_deref().unwrap_or(&input_data)).unwrap();
+                    //结果仍保存原始形式，填入反向转换时可以解析
+                    data.set_output_data(settings.format_f32(value));
+                    ui.add(Label::new(RichText::new(t("f32浮点数")).color(Color32::BLUE)));
+                    //科学计数法的结果不分组，避免在指数前插入分隔符
+                    if let Some(label) = settings.special_value_label(value as f64) {
+                        copyable(ui, label)
+                    } else if data.output_data.contains('e') {
+                        copyable(ui, &data.output_data)
+                    } else {
+                        copyable(ui, &settings.group(&data.output_data, 10))
+                    }
             }
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_suffix_is_ignored() {
+        //输入框不把后缀标红，转换时也要忽略
+        let mut data = Data::new();
+        data.input_data = "0x3F80_0000u32".to_string();
+        run_panel(base32_f32, &mut data, &Settings::new());
+        assert!(data.get_data_error() == &DataError::Nice);
+        assert_eq!(data.output_data, "1");
+    }
+}
@@ -0,0 +1,96 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+
+pub fn base8(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut input_data = String::new();
+    let mut invalid = Vec::new();
+    let mut lost_focus = false;
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("8进制数")).color(Color32::BLUE)).on_hover_text(t("可输入下划线做视觉分割，可带0o前缀"));
+        let response = radix_input_edit(data, settings, 8, ui);
+        lost_focus = response.lost_focus();
+        data.has_focus = response.has_focus();
+        example_menu(data, "8进制数", ui);
+        history_menu(data, ui);
+        //随机右移，让生成的数值长短不一
+        random_button(data, ui, |r| to_radix(r >> (r % 64), 8));
+
+        //允许输入"_"做视觉区分，忽略"0o"前缀和u8等类型后缀
+        let raw_data = strip_radix_prefix(strip_type_suffix(&data.ref_input_data().replace("_", "")), 8).to_string();
+
+        if raw_data.is_empty() {
+            data.set_data_error(DataError::LenNull);
+        }
+
+        invalid = invalid_chars(&raw_data, 8);
+        input_data = raw_data
+            .chars()
+            .filter(|c| {
+                if !c.is_digit(8) {
+                    data.set_data_error(DataError::FormatError);
+                    false
+                } else {
+                    true
+                }
+            })
+            .collect();
+    });
+    //22位8进制数可能超出u64，以解析结果为准
+    let mut number_data: u64 = 0;
+    let mut overflowed = false;
+    match parse_radix(&input_data, 8) {
+        Ok(data) => (number_data, overflowed) = settings.mask(data),
+        Err(_) => {
+            if data.get_data_error() == &DataError::Nice {
+                 data.set_data_error(DataError::LenOver);
+            }
+        }
+    };
+    ui.horizontal(|ui| {
+        match data.get_data_error() {
+            DataError::FormatError => format_error(ui, t("请输入8进制字符"), &invalid),
+            DataError::LenNull => ui.colored_label(Color32::RED, t("请输入数值")),
+            DataError::LenOver => ui.colored_label(Color32::RED, t("数值大于u64最大值:{}").replace("{}", &u64::MAX.to_string())),
+            DataError::Nice => {
+                    let decimal = to_radix(number_data, 10);
+                    let hex = settings.hex_case(settings.pad(to_radix(number_data, 16), 16));
+                    ui.add(Label::new(RichText::new(t("10进制数:")).color(Color32::BLUE)));
+                    copyable(ui, &settings.group(&decimal, 10));
+                    ui.separator();
+                    ui.add(Label::new(RichText::new(t("16进制数:")).color(Color32::BLUE)));
+                    let response = radix_copyable(ui, &settings.group(&hex, 16), 16, settings);
+                    data.set_output_data(radix_results(&[(10, &decimal), (16, &hex)]));
+                    response
+            }
+        }
+    });
+    if data.get_data_error() == &DataError::Nice {
+        overflow_note(overflowed, settings, ui);
+        bit_stats(number_data, None, ui);
+        code_literals(number_data, settings, ui);
+    }
+    //失去焦点且转换成功时记录历史
+    if lost_focus && data.get_data_error() == &DataError::Nice {
+        data.push_history();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn octal_panel_converts() {
+        let mut data = Data::new();
+        data.input_data = "0o1_777".to_string();
+        run_panel(base8, &mut data, &Settings::default());
+        assert!(data.get_data_error() == &DataError::Nice);
+        assert_eq!(data.output_data, "1023 0x3ff");
+    }
+}
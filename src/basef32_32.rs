@@ -1,42 +1,173 @@
+use crate::i18n::*;
 use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
 use eframe::egui;
 use egui::*;
+use crate::convert::*;
 
-pub fn basef32_32(data: &mut Data, ui: &mut Ui) {
+pub fn basef32_32(data: &mut Data, settings: &Settings, ui: &mut Ui) {
     data.set_data_error(DataError::Nice);
     let mut input_data : f32 = 0.0;
+    let mut batch_data: Vec<String> = Vec::new();
+    let mut in_progress = false;
+    //整数输入按数值编码(5→5.0)还是按位重解释(5→0x00000005)，存放在egui的临时数据中
+    let reinterpret_id = ui.id().with("f32位重解释");
+    let mut reinterpret = ui.data(|d| d.get_temp::<bool>(reinterpret_id)).unwrap_or(false);
     ui.horizontal(|ui| {
-        ui.label(RichText::from("输入f32数据").color(Color32::BLUE)).on_hover_text("可输入下划线做视觉分割");
-        let text_edit = TextEdit::singleline(&mut data.input_data)
-        .desired_width(400.0);
-        ui.add(text_edit);
+        ui.label(RichText::from(t("输入f32数据")).color(Color32::BLUE)).on_hover_text(t("可输入下划线做视觉分割，支持科学计数法(如1.5e-3、2E10)，多个数据用逗号或换行分隔"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+        example_menu(data, "f32数据", ui);
+        random_button(data, ui, |r| ((r as u32) as f32 / u32::MAX as f32 * 2000.0 - 1000.0).to_string());
+        ui.separator();
+        ui.selectable_value(&mut reinterpret, false, t("数值编码")).on_hover_text(t("把输入作为数值编码，整数5按5.0编码为40A00000"));
+        ui.selectable_value(&mut reinterpret, true, t("位重解释")).on_hover_text(t("把整数输入当作f32的32位位模式，5编码为00000005，负数按补码"));
 
         //允许输入"_"做视觉区分
-        let raw_data = data.ref_input_data().clone().replace("_", "");
+        let raw_data = data.ref_input_data().replace("_", "");
 
+        //逗号或换行分隔时按批量模式逐个转换
+        if raw_data.contains([',', '\n']) {
+            batch_data = raw_data
+                .split([',', '\n'])
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect();
+            if !batch_data.is_empty() {
+                return;
+            }
+        }
+
+        if reinterpret {
+            match int_bits_to_f32(&raw_data) {
+                Some(number) => input_data = number,
+                None if raw_data.is_empty() => data.set_data_error(DataError::LenNull),
+                None => data.set_data_error(DataError::FormatError),
+            }
+            return;
+        }
         match raw_data.parse::<f32>() {
             Ok(number) => input_data = number,
             Err(_) => {
                 if raw_data.is_empty() {
                     data.set_data_error(DataError::LenNull);
+                }else if let Some(number) = parse_unfinished(&raw_data) {
+                    input_data = number;
+                    in_progress = true;
                 }else {
                 data.set_data_error(DataError::FormatError);
                 }
             },
         }
     });
+    ui.data_mut(|d| d.insert_temp(reinterpret_id, reinterpret));
+    if !batch_data.is_empty() {
+        //有一项无法转换时整批视为无效，不更新结果
+        match batch_f32_32(&batch_data, reinterpret, settings, ui) {
+            Some(string_data) => data.set_output_data(string_data),
+            None => data.set_data_error(DataError::FormatError),
+        }
+        return;
+    }
     ui.horizontal(|ui| {
         match data.get_data_error() {
-            DataError::FormatError => ui.colored_label(Color32::RED, "请输入f32数据"),
-            DataError::LenNull => ui.colored_label(Color32::RED, "请输入数值"),
+            DataError::FormatError if reinterpret => ui.colored_label(Color32::RED, t("位重解释需要32位以内的整数")),
+            DataError::FormatError => ui.colored_label(Color32::RED, t("请输入f32数据")),
+            DataError::LenNull => ui.colored_label(Color32::RED, t("请输入数值")),
+            DataError::Nice if in_progress => ui.colored_label(Color32::GRAY, t("输入未完成")),
             DataError::Nice => {
-                    let number_data = input_data.to_bits();
-                    let string_data = format!("{:08x}", number_data);
+                    let string_data = settings.hex_case(f32_to_hex(input_data));
+                    ui.add(Label::new(RichText::new(t("16进制编码")).color(Color32::BLUE)));
+                    let response = radix_copyable(ui, &settings.group(&string_data, 16), 16, settings);
                     data.set_output_data(string_data);
-                    ui.add(Label::new(RichText::new("16进制编码").color(Color32::BLUE)));
-                    ui.monospace(data.get_output_data())
+                    //位重解释时显示这些位对应的f32数值
+                    if reinterpret {
+                        ui.separator();
+                        ui.add(Label::new(RichText::new(t("f32浮点数")).color(Color32::BLUE)));
+                        copyable(ui, settings.special_value_label(input_data as f64).unwrap_or(&settings.format_f32(input_data)));
+                    }
+                    response
             }
-            _ => ui.colored_label(Color32::RED, "请输入f32数据")
+            _ => ui.colored_label(Color32::RED, t("请输入f32数据"))
         }
     });
+    //输入未完成时按已输入的部分预览
+    if data.get_data_error() == &DataError::Nice {
+        f32_breakdown(input_data, settings, ui);
+    }
+}
+
+//"1e"、"-"、"2.5e-"等还没输完的输入，去掉末尾的指数符号及其后的正负号后能解析时返回该值
+//"1-"、"1e2e"这类符号位置不对或有多个指数的输入不算未完成
+fn parse_unfinished(input: &str) -> Option<f32> {
+    if matches!(input, "-" | "+") {
+        return Some(0.0);
+    }
+    let head = input.strip_suffix(['+', '-']).unwrap_or(input).strip_suffix(['e', 'E'])?;
+    if head.contains(['e', 'E']) {
+        return None;
+    }
+    head.parse().ok()
+}
+
+//批量模式每个数据单独一行，格式错误的条目单独提示，其余照常转换
+//全部转换成功时返回各项的编码，每行一个
+fn batch_f32_32(batch_data: &[String], reinterpret: bool, settings: &Settings, ui: &mut Ui) -> Option<String> {
+    let mut converted = Vec::new();
+    let mut failed = false;
+    ui.add(Label::new(RichText::new(t("16进制编码")).color(Color32::BLUE)));
+    for item in batch_data {
+        ui.horizontal(|ui| {
+            ui.monospace(item);
+            let number = if reinterpret { int_bits_to_f32(item) } else { item.parse::<f32>().ok() };
+            match number {
+                Some(number) => {
+                    let hex = settings.hex_case(f32_to_hex(number));
+                    radix_copyable(ui, &hex, 16, settings);
+                    converted.push(hex);
+                }
+                None => {
+                    failed = true;
+                    ui.colored_label(Color32::RED, t("请输入f32数据"));
+                }
+            }
+        });
+    }
+    (!failed).then(|| converted.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(input: &str) -> (String, bool) {
+        let mut data = Data::new();
+        data.input_data = input.to_string();
+        run_panel(basef32_32, &mut data, &Settings::new());
+        let valid = data.get_data_error() == &DataError::Nice;
+        (data.output_data, valid)
+    }
+
+    #[test]
+    fn scientific_notation() {
+        assert_eq!(encode("1.5e-3"), ("3ac49ba6".to_string(), true));
+        assert_eq!(encode("2E10"), ("509502f9".to_string(), true));
+        assert_eq!(encode("-2.5E+1"), ("c1c80000".to_string(), true));
+    }
+
+    #[test]
+    fn incomplete_exponent_is_not_a_value() {
+        //"1e"只是输入未完成，不产生编码
+        assert_eq!(parse_unfinished("1e"), Some(1.0));
+        assert_eq!(parse_unfinished("2.5E-"), Some(2.5));
+        assert_eq!(encode("1e"), (String::new(), true));
+        assert_eq!(validate_f32("1e"), Validation::Malformed);
+    }
+
+    #[test]
+    fn malformed_exponent() {
+        for input in ["1e2e3", "1e2e", "1.5e-3-", "1-", "1-e3", "e5"] {
+            assert_eq!(encode(input), (String::new(), false), "{}", input);
+        }
+    }
 }
@@ -0,0 +1,74 @@
+use crate::i18n::*;
+use eframe::egui;
+use egui::*;
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+//各版本的更新内容，新版本放在最前，条目为中文，界面以它为键查找译文
+const CHANGELOG: &[(&str, &[&str])] = &[
+    (
+        "0.2.0",
+        &[
+            "各面板新增\"示例\"菜单",
+            "新增字节检查器，一次查看字节转储、数值解释、f32编码分解和Base64",
+            "新增时间戳解析，支持Unix秒、毫秒、FILETIME和NTP",
+            "16进制数可按补码显示有符号数，10进制数可显示大端序和小端序",
+            "f32编码支持把整数按位重解释",
+            "智能输入可逐行转换粘贴的多行数值",
+            "设置中新增复制时带进制前缀、输出分组、保留上次结果和紧凑模式",
+            "设置在重启后保留",
+        ],
+    ),
+    ("0.1.0", &["首个版本"]),
+];
+
+//升级后首次启动时自动打开更新说明；没有记录过版本(新安装)或版本没有变大时不打开
+//last_seen为上次看过更新说明的版本，版本号按"."分隔的数字逐段比较，无法解析时视为旧版本
+pub fn should_show(last_seen: Option<&str>, current: &str) -> bool {
+    let parse = |version: &str| version.split('.').map(|part| part.parse::<u64>().ok()).collect::<Option<Vec<u64>>>();
+    match (last_seen, parse(current)) {
+        (None, _) => false,
+        (Some(last_seen), Some(current)) => parse(last_seen).is_none_or(|last_seen| last_seen < current),
+        (Some(last_seen), None) => last_seen != current,
+    }
+}
+
+//底部栏的"更新内容"按钮，点击后打开当前版本的更新说明窗口
+pub fn changelog_button(open: &mut bool, ui: &mut Ui) {
+    if ui.button(format!("{} v{}", t("更新内容"), VERSION)).clicked() {
+        *open = !*open;
+    }
+}
+
+pub fn changelog_window(open: &mut bool, ctx: &egui::Context) {
+    Window::new(t("更新内容")).id(Id::new("更新内容")).open(open).resizable(false).show(ctx, |ui| {
+        for (version, notes) in CHANGELOG {
+            let title = if *version == VERSION { format!("v{} ({})", version, t("当前版本")) } else { format!("v{}", version) };
+            ui.label(RichText::new(title).strong());
+            for note in *notes {
+                ui.label(format!("• {}", t(note)));
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shown_once_after_upgrade() {
+        assert!(should_show(Some("0.1.0"), "0.2.0"));
+        assert!(should_show(Some("0.9.3"), "0.10.0"));
+        assert!(!should_show(Some("0.2.0"), "0.2.0"));
+        assert!(!should_show(Some("0.3.0"), "0.2.0"));
+        //新安装没有可说明的更新
+        assert!(!should_show(None, "0.2.0"));
+        assert!(should_show(Some("损坏的记录"), "0.2.0"));
+    }
+
+    #[test]
+    fn current_version_is_listed_first() {
+        assert_eq!(CHANGELOG[0].0, VERSION);
+    }
+}
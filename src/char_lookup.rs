@@ -0,0 +1,63 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::unicode::*;
+
+pub fn char_lookup(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut found: Vec<CharInfo> = Vec::new();
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("字符查询")).color(Color32::BLUE)).on_hover_text(t("输入单个字符，或英文名称片段(如ACUTE)"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+
+        let mut chars = data.input_data.chars();
+        //单个字符直接查询，否则按名称片段查询
+        match (chars.next(), chars.next()) {
+            (None, _) => data.set_data_error(DataError::LenNull),
+            (Some(ch), None) => found.push(CharInfo::new(ch)),
+            _ => {
+                found = lookup_name(&data.input_data)
+                    .into_iter()
+                    .map(|(_, ch)| CharInfo::new(ch))
+                    .collect();
+                if found.is_empty() {
+                    data.set_data_error(DataError::FormatError);
+                }
+            }
+        }
+    });
+    match data.get_data_error() {
+        DataError::FormatError => {
+            ui.colored_label(Color32::RED, t("未找到匹配的字符"));
+        }
+        DataError::LenNull => {
+            ui.colored_label(Color32::RED, t("请输入字符或名称"));
+        }
+        _ => {
+            for info in found {
+                ui.horizontal(|ui| {
+                    copyable(ui, &info.ch.to_string());
+                    ui.add(Label::new(RichText::new(t("码点:")).color(Color32::BLUE)));
+                    copyable(ui, &format!("U+{:04X}", info.code_point));
+                    ui.separator();
+                    ui.add(Label::new(RichText::new("UTF-8:").color(Color32::BLUE)));
+                    copyable(ui, &hex_units(info.utf8.iter().map(|b| format!("{:02X}", b))));
+                    ui.separator();
+                    ui.add(Label::new(RichText::new("UTF-16:").color(Color32::BLUE)));
+                    copyable(ui, &hex_units(info.utf16.iter().map(|u| format!("{:04X}", u))));
+                    if let Some(name) = info.name() {
+                        ui.separator();
+                        ui.label(name);
+                    }
+                });
+            }
+        }
+    }
+}
+
+fn hex_units(units: impl Iterator<Item = String>) -> String {
+    units.collect::<Vec<String>>().join(" ")
+}
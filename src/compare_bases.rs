@@ -0,0 +1,73 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+
+pub fn compare_bases(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut table = None;
+    //是否显示base32/base64列，存放在egui的临时数据中
+    let extra_id = ui.id().with("进制对照表base32/base64");
+    let mut show_extra = ui.data(|d| d.get_temp::<bool>(extra_id)).unwrap_or(false);
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("进制对照表")).color(Color32::BLUE)).on_hover_text(t("自动识别进制，一行列出各进制的表示，数值不限于64位"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+        ui.checkbox(&mut show_extra, "base32/base64");
+
+        //只输入了前缀时按未输入完处理，不提示无法识别
+        if data.input_data.trim().is_empty() || is_bare_prefix(&data.input_data) {
+            data.set_data_error(DataError::LenNull);
+            return;
+        }
+        table = detect_number_format(&data.input_data).and_then(|(radix, digits)| BaseTable::new(&digits, radix));
+        if table.is_none() {
+            data.set_data_error(DataError::FormatError);
+        }
+    });
+    ui.data_mut(|d| d.insert_temp(extra_id, show_extra));
+    ui.horizontal(|ui| {
+        match (data.get_data_error(), table) {
+            (DataError::LenNull, _) => {
+                ui.colored_label(Color32::RED, t("请输入数值"));
+            }
+            (_, Some(table)) => {
+                let group = |digits: &str, radix: u32| settings.group(digits, radix);
+                let mut columns = vec![
+                    (t("2进制"), group(&table.binary, 2), Some(2)),
+                    (t("8进制"), group(&table.octal, 8), Some(8)),
+                    (t("10进制"), group(&table.decimal, 10), Some(10)),
+                    (t("16进制"), settings.hex_case(group(&table.hex, 16)), Some(16)),
+                ];
+                if show_extra {
+                    //base32/base64没有数字前缀，按原样复制
+                    columns.push(("base32", table.base32, None));
+                    columns.push(("base64", table.base64, None));
+                }
+                data.set_output_data(radix_results(&[(2, &table.binary), (8, &table.octal), (10, &table.decimal), (16, &table.hex)]));
+                Grid::new("进制对照表").striped(true).show(ui, |ui| {
+                    for (label, _, _) in &columns {
+                        ui.label(RichText::new(*label).color(Color32::BLUE));
+                    }
+                    ui.end_row();
+                    for (_, digits, radix) in &columns {
+                        ui.horizontal(|ui| match radix {
+                            Some(radix) => radix_copyable(ui, digits, *radix, settings),
+                            None => copyable(ui, digits),
+                        });
+                    }
+                    ui.end_row();
+                });
+                //超过64位时没有对应的定宽整数类型
+                if table.bits > 64 {
+                    ui.colored_label(Color32::GRAY, t("{}位，超过u64范围").replace("{}", &table.bits.to_string()));
+                }
+            }
+            (_, None) => {
+                ui.colored_label(Color32::RED, t("无法识别进制，请使用对应的进制输入框"));
+            }
+        }
+    });
+}
@@ -0,0 +1,45 @@
+//! zlib/gzip/deflate解压，需启用compression特性
+
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use std::io::{self, Read};
+
+/// 自动识别出的压缩格式
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CompressionFormat {
+    Gzip,
+    Zlib,
+    Deflate,
+}
+
+impl CompressionFormat {
+    /// 根据头部识别格式：gzip以1F 8B开头，zlib头部两字节能被31整除且压缩方法为8，其余按原始deflate处理
+    pub fn detect(bytes: &[u8]) -> CompressionFormat {
+        match bytes {
+            [0x1f, 0x8b, ..] => CompressionFormat::Gzip,
+            [cmf, flg, ..] if cmf & 0x0f == 8 && (u16::from(*cmf) << 8 | u16::from(*flg)) % 31 == 0 => {
+                CompressionFormat::Zlib
+            }
+            _ => CompressionFormat::Deflate,
+        }
+    }
+}
+
+/// 自动识别格式并解压，数据不是有效的压缩数据时返回错误
+///
+/// ```
+/// use number_conversion::compression::{decompress, CompressionFormat};
+/// //zlib压缩的"hello"
+/// let bytes = [0x78, 0x9c, 0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x07, 0x00, 0x06, 0x2c, 0x02, 0x15];
+/// assert_eq!(decompress(&bytes).unwrap(), (CompressionFormat::Zlib, b"hello".to_vec()));
+/// assert!(decompress(&[0x78, 0x9c, 0x00]).is_err());
+/// ```
+pub fn decompress(bytes: &[u8]) -> io::Result<(CompressionFormat, Vec<u8>)> {
+    let format = CompressionFormat::detect(bytes);
+    let mut output = Vec::new();
+    match format {
+        CompressionFormat::Gzip => GzDecoder::new(bytes).read_to_end(&mut output)?,
+        CompressionFormat::Zlib => ZlibDecoder::new(bytes).read_to_end(&mut output)?,
+        CompressionFormat::Deflate => DeflateDecoder::new(bytes).read_to_end(&mut output)?,
+    };
+    Ok((format, output))
+}
@@ -0,0 +1,1101 @@
+//! 纯转换函数，界面中的各个转换面板都基于这些函数实现
+
+use crate::encoding::BASE64_ALPHABET;
+use num::BigUint;
+use std::num::{ParseFloatError, ParseIntError};
+
+/// 按指定进制解析整数，忽略用于视觉分割的下划线
+///
+/// ```
+/// use number_conversion::convert::parse_radix;
+/// assert_eq!(parse_radix("1111_0000", 2), Ok(240));
+/// assert!(parse_radix("12", 2).is_err());
+/// ```
+pub fn parse_radix(input: &str, radix: u32) -> Result<u64, ParseIntError> {
+    u64::from_str_radix(&input.replace('_', ""), radix)
+}
+
+/// 去掉进制的常用前缀：2进制"0b"、8进制"0o"、16进制"0x"，不区分大小写
+///
+/// ```
+/// use number_conversion::convert::strip_radix_prefix;
+/// assert_eq!(strip_radix_prefix("0xFF", 16), "FF");
+/// assert_eq!(strip_radix_prefix("0b1010", 2), "1010");
+/// assert_eq!(strip_radix_prefix("0x", 16), "");
+/// assert_eq!(strip_radix_prefix("0b1010", 16), "0b1010");
+/// ```
+pub fn strip_radix_prefix(input: &str, radix: u32) -> &str {
+    let prefix = match radix {
+        2 => "0b",
+        8 => "0o",
+        16 => "0x",
+        _ => return input,
+    };
+    match input.get(..2) {
+        Some(head) if head.eq_ignore_ascii_case(prefix) => &input[2..],
+        _ => input,
+    }
+}
+
+/// 去掉从源码中复制来的整数类型后缀：Rust的u8~u128/usize/i8~i128/isize，C的U/L/UL/ULL等
+///
+/// 这些后缀的字母都不是16进制数字，不会误删数字部分
+///
+/// ```
+/// use number_conversion::convert::strip_type_suffix;
+/// assert_eq!(strip_type_suffix("255u8"), "255");
+/// assert_eq!(strip_type_suffix("0xFFi32"), "0xFF");
+/// assert_eq!(strip_type_suffix("0xFFFFULL"), "0xFFFF");
+/// assert_eq!(strip_type_suffix("100usize"), "100");
+/// assert_eq!(strip_type_suffix("0xAB"), "0xAB");
+/// ```
+pub fn strip_type_suffix(input: &str) -> &str {
+    const RUST_SUFFIXES: [&str; 12] = [
+        "u128", "i128", "usize", "isize", "u64", "i64", "u32", "i32", "u16", "i16", "u8", "i8",
+    ];
+    for suffix in RUST_SUFFIXES {
+        if let Some(digits) = input.strip_suffix(suffix) {
+            return digits;
+        }
+    }
+    input.trim_end_matches(['u', 'U', 'l', 'L'])
+}
+
+/// 输入只有0b/0o/0x前缀、还没有数字，即还没输入完
+///
+/// ```
+/// use number_conversion::convert::is_bare_prefix;
+/// assert!(is_bare_prefix("0x"));
+/// assert!(is_bare_prefix(" 0B "));
+/// assert!(!is_bare_prefix("0x1"));
+/// assert!(!is_bare_prefix("0"));
+/// assert!(!is_bare_prefix(""));
+/// ```
+pub fn is_bare_prefix(input: &str) -> bool {
+    let input = input.trim();
+    !input.is_empty() && [2, 8, 16].into_iter().any(|radix| strip_radix_prefix(input, radix).is_empty())
+}
+
+/// 自动识别整数的进制，返回进制和去掉前后缀、下划线后的数字部分
+///
+/// 支持前缀0x/0b/0o、后缀h/b/o、两者同时出现的"0xFFh"，以及u8、i32、ULL等类型后缀；
+/// 纯数字按10进制，含a-f字母时按16进制，无法识别时返回None
+///
+/// ```
+/// use number_conversion::convert::detect_number_format;
+/// assert_eq!(detect_number_format("0xFF"), Some((16, "FF".to_string())));
+/// assert_eq!(detect_number_format("1010b"), Some((2, "1010".to_string())));
+/// assert_eq!(detect_number_format("777o"), Some((8, "777".to_string())));
+/// assert_eq!(detect_number_format("255"), Some((10, "255".to_string())));
+/// assert_eq!(detect_number_format("1f"), Some((16, "1f".to_string())));
+/// assert_eq!(detect_number_format("0xFFh"), Some((16, "FF".to_string())));
+/// assert_eq!(detect_number_format("0x1b"), Some((16, "1b".to_string())));
+/// assert_eq!(detect_number_format("255u8"), Some((10, "255".to_string())));
+/// assert_eq!(detect_number_format("0xFFFF_FFFFu32"), Some((16, "FFFFFFFF".to_string())));
+/// assert_eq!(detect_number_format("xyz"), None);
+/// ```
+pub fn detect_number_format(input: &str) -> Option<(u32, String)> {
+    let input = input.trim().replace('_', "");
+    let input = strip_type_suffix(&input).to_string();
+    let all_digits = |digits: &str, radix: u32| {
+        !digits.is_empty() && digits.chars().all(|c| c.is_digit(radix))
+    };
+    for radix in [2, 8, 16] {
+        let digits = strip_radix_prefix(&input, radix);
+        if digits.len() != input.len() {
+            //带0x前缀时，末尾的"h"是多余的16进制后缀
+            let digits = match digits.strip_suffix(['h', 'H']) {
+                Some(stripped) if radix == 16 => stripped,
+                _ => digits,
+            };
+            return all_digits(digits, radix).then(|| (radix, digits.to_string()));
+        }
+    }
+    //后缀形式，"b"同时是16进制数字，只有前面全是0/1时才按2进制处理
+    for (suffix, radix) in [('h', 16), ('b', 2), ('o', 8)] {
+        if let Some(digits) = input
+            .strip_suffix(suffix)
+            .or_else(|| input.strip_suffix(suffix.to_ascii_uppercase()))
+        {
+            if all_digits(digits, radix) {
+                return Some((radix, digits.to_string()));
+            }
+        }
+    }
+    if all_digits(&input, 10) {
+        Some((10, input))
+    } else if all_digits(&input, 16) {
+        Some((16, input))
+    } else {
+        None
+    }
+}
+
+/// 10进制输入以0开头且多于一位、各位都是8进制数字时，在C等语言中会被当作8进制，返回8进制的数字部分
+///
+/// ```
+/// use number_conversion::convert::leading_zero_octal;
+/// assert_eq!(leading_zero_octal("0755"), Some("755".to_string()));
+/// assert_eq!(leading_zero_octal("00_17"), Some("17".to_string()));
+/// assert_eq!(leading_zero_octal("00"), Some("0".to_string()));
+/// assert_eq!(leading_zero_octal("0"), None);
+/// assert_eq!(leading_zero_octal("755"), None);
+/// assert_eq!(leading_zero_octal("089"), None);
+/// ```
+pub fn leading_zero_octal(input: &str) -> Option<String> {
+    let digits = input.trim().replace('_', "");
+    let valid = digits.len() > 1 && digits.starts_with('0') && digits.chars().all(|c| c.is_digit(8));
+    valid.then(|| match digits.trim_start_matches('0') {
+        "" => "0".to_string(),
+        octal => octal.to_string(),
+    })
+}
+
+/// 粘贴的多行输入按行拆分，去掉空行和首尾空白；只有一行时返回None
+///
+/// ```
+/// use number_conversion::convert::split_lines;
+/// assert_eq!(split_lines("0xFF\r\n 255 \n\n"), Some(vec!["0xFF", "255"]));
+/// assert_eq!(split_lines("0xFF\n"), None);
+/// assert_eq!(split_lines("0xFF"), None);
+/// ```
+pub fn split_lines(input: &str) -> Option<Vec<&str>> {
+    let lines: Vec<&str> = input.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+    (lines.len() > 1).then_some(lines)
+}
+
+/// 输入中不属于该进制的字符，按首次出现的顺序去重
+///
+/// ```
+/// use number_conversion::convert::invalid_chars;
+/// assert_eq!(invalid_chars("A1G2", 16), vec!['G']);
+/// assert_eq!(invalid_chars("A1G2", 2), vec!['A', 'G', '2']);
+/// assert_eq!(invalid_chars("1x2x", 10), vec!['x']);
+/// assert!(invalid_chars("ff", 16).is_empty());
+/// ```
+pub fn invalid_chars(input: &str, radix: u32) -> Vec<char> {
+    let mut invalid = Vec::new();
+    for c in input.chars().filter(|c| !c.is_digit(radix)) {
+        if !invalid.contains(&c) {
+            invalid.push(c);
+        }
+    }
+    invalid
+}
+
+/// 只检查输入而不做转换的结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum Validation {
+    Valid,
+    Empty,
+    /// 不属于该进制的字符，按首次出现的顺序去重
+    InvalidChars(Vec<char>),
+    /// 数字位数超过上限，内容为实际位数
+    TooLong(usize),
+    /// 字符都合法但整体无法解析，如浮点数"1.2.3"
+    Malformed,
+}
+
+/// 按各进制面板的规则检查整数输入，可用于表单校验
+///
+/// 忽略下划线、进制前缀和类型后缀；同时有非法字符和超长时报告非法字符
+///
+/// ```
+/// use number_conversion::convert::{validate_radix, Validation};
+/// assert_eq!(validate_radix("0xFF_FFu16", 16, 16), Validation::Valid);
+/// assert_eq!(validate_radix("0x", 16, 16), Validation::Empty);
+/// assert_eq!(validate_radix("12G", 16, 16), Validation::InvalidChars(vec!['G']));
+/// assert_eq!(validate_radix("1111_0000_1", 2, 8), Validation::TooLong(9));
+/// ```
+pub fn validate_radix(input: &str, radix: u32, max_len: usize) -> Validation {
+    let digits = input.replace('_', "");
+    let digits = strip_radix_prefix(strip_type_suffix(&digits), radix);
+    let invalid = invalid_chars(digits, radix);
+    if digits.is_empty() {
+        Validation::Empty
+    } else if !invalid.is_empty() {
+        Validation::InvalidChars(invalid)
+    } else if digits.len() > max_len {
+        Validation::TooLong(digits.len())
+    } else {
+        Validation::Valid
+    }
+}
+
+/// 检查f32输入，支持下划线和科学计数法
+///
+/// ```
+/// use number_conversion::convert::{validate_f32, Validation};
+/// assert_eq!(validate_f32("1_000.5e-3"), Validation::Valid);
+/// assert_eq!(validate_f32(" "), Validation::Empty);
+/// assert_eq!(validate_f32("1.2x"), Validation::InvalidChars(vec!['x']));
+/// assert_eq!(validate_f32("1.2.3"), Validation::Malformed);
+/// ```
+pub fn validate_f32(input: &str) -> Validation {
+    let number = input.trim().replace('_', "");
+    if number.is_empty() {
+        return Validation::Empty;
+    }
+    if number.parse::<f32>().is_ok() {
+        return Validation::Valid;
+    }
+    let mut invalid = Vec::new();
+    for c in number.chars().filter(|c| !c.is_ascii_digit() && !".eE+-".contains(*c)) {
+        if !invalid.contains(&c) {
+            invalid.push(c);
+        }
+    }
+    if invalid.is_empty() {
+        Validation::Malformed
+    } else {
+        Validation::InvalidChars(invalid)
+    }
+}
+
+/// 输入长度相对上限的状态
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LengthStatus {
+    Normal,
+    /// 达到上限的80%
+    Near,
+    /// 正好达到上限
+    Full,
+    Over,
+}
+
+/// 按输入长度和上限判断状态，用于在超出之前提示
+///
+/// ```
+/// use number_conversion::convert::{length_status, LengthStatus};
+/// assert_eq!(length_status(12, 16), LengthStatus::Normal);
+/// assert_eq!(length_status(13, 16), LengthStatus::Near);
+/// assert_eq!(length_status(16, 16), LengthStatus::Full);
+/// assert_eq!(length_status(17, 16), LengthStatus::Over);
+/// assert_eq!(length_status(0, 0), LengthStatus::Full);
+/// ```
+pub fn length_status(len: usize, max: usize) -> LengthStatus {
+    if len > max {
+        LengthStatus::Over
+    } else if len == max {
+        LengthStatus::Full
+    } else if len * 10 >= max * 8 {
+        LengthStatus::Near
+    } else {
+        LengthStatus::Normal
+    }
+}
+
+/// 给2、8、16进制的数字加上0b、0o、0x前缀，负号放在前缀之前，其他进制原样返回
+///
+/// ```
+/// use number_conversion::convert::with_radix_prefix;
+/// assert_eq!(with_radix_prefix("ff_ff", 16), "0xff_ff");
+/// assert_eq!(with_radix_prefix("1010", 2), "0b1010");
+/// assert_eq!(with_radix_prefix("-17", 8), "-0o17");
+/// assert_eq!(with_radix_prefix("255", 10), "255");
+/// ```
+pub fn with_radix_prefix(digits: &str, radix: u32) -> String {
+    let prefix = match radix {
+        2 => "0b",
+        8 => "0o",
+        16 => "0x",
+        _ => return digits.to_string(),
+    };
+    match digits.strip_prefix('-') {
+        Some(unsigned) => format!("-{}{}", prefix, unsigned),
+        None => format!("{}{}", prefix, digits),
+    }
+}
+
+/// 将整数转换为指定进制的字符串(小写)
+///
+/// ```
+/// use number_conversion::convert::to_radix;
+/// assert_eq!(to_radix(255, 16), "ff");
+/// assert_eq!(to_radix(5, 2), "101");
+/// ```
+pub fn to_radix(value: u64, radix: u32) -> String {
+    BigUint::from(value).to_str_radix(radix)
+}
+
+/// 同一个数值在2、8、10、16进制下的表示(小写)
+///
+/// ```
+/// use number_conversion::convert::BaseConversions;
+/// let conversions = BaseConversions::new(255);
+/// assert_eq!(conversions.binary, "11111111");
+/// assert_eq!(conversions.octal, "377");
+/// assert_eq!(conversions.decimal, "255");
+/// assert_eq!(conversions.hex, "ff");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseConversions {
+    pub binary: String,
+    pub octal: String,
+    pub decimal: String,
+    pub hex: String,
+}
+
+impl BaseConversions {
+    pub fn new(value: u64) -> BaseConversions {
+        BaseConversions {
+            binary: to_radix(value, 2),
+            octal: to_radix(value, 8),
+            decimal: to_radix(value, 10),
+            hex: to_radix(value, 16),
+        }
+    }
+}
+
+/// 同一个数值在各进制下的表示(小写)，数值不限于64位
+///
+/// base32使用0-9a-v作数字，base64按位值使用Base64字母表，二者都是进位制表示而不是字节编码
+///
+/// ```
+/// use number_conversion::convert::BaseTable;
+/// let table = BaseTable::new("255", 10).unwrap();
+/// assert_eq!(table.binary, "11111111");
+/// assert_eq!(table.octal, "377");
+/// assert_eq!(table.decimal, "255");
+/// assert_eq!(table.hex, "ff");
+/// assert_eq!(table.base32, "7v");
+/// assert_eq!(table.base64, "D/");
+/// assert_eq!(table.bits, 8);
+/// let large = BaseTable::new("1_0000_0000_0000_0000_0000", 16).unwrap();
+/// assert_eq!(large.decimal, "1208925819614629174706176");
+/// assert_eq!(large.bits, 81);
+/// assert_eq!(BaseTable::new("0", 10).unwrap().base64, "A");
+/// assert!(BaseTable::new("12", 2).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaseTable {
+    pub binary: String,
+    pub octal: String,
+    pub decimal: String,
+    pub hex: String,
+    pub base32: String,
+    pub base64: String,
+    /// 有效位数，超过64时不适用于定宽整数
+    pub bits: u64,
+}
+
+impl BaseTable {
+    pub fn new(digits: &str, radix: u32) -> Option<BaseTable> {
+        let value = BigUint::parse_bytes(digits.replace('_', "").as_bytes(), radix)?;
+        let base64 = value.to_radix_be(64).iter().map(|&d| BASE64_ALPHABET[d as usize] as char).collect();
+        Some(BaseTable {
+            binary: value.to_str_radix(2),
+            octal: value.to_str_radix(8),
+            decimal: value.to_str_radix(10),
+            hex: value.to_str_radix(16),
+            base32: value.to_str_radix(32),
+            base64,
+            bits: value.bits(),
+        })
+    }
+}
+
+/// 去掉前导零后的有效位数，0的有效位数为0
+///
+/// ```
+/// use number_conversion::convert::significant_bits;
+/// assert_eq!(significant_bits(0xFF), 8);
+/// assert_eq!(significant_bits(0x100), 9);
+/// assert_eq!(significant_bits(0), 0);
+/// ```
+pub fn significant_bits(value: u64) -> u32 {
+    u64::BITS - value.leading_zeros()
+}
+
+/// 容纳有效位所需的字节数
+///
+/// ```
+/// use number_conversion::convert::significant_bytes;
+/// assert_eq!(significant_bytes(0xFF), 1);
+/// assert_eq!(significant_bytes(0x100), 2);
+/// ```
+pub fn significant_bytes(value: u64) -> u32 {
+    significant_bits(value).div_ceil(8)
+}
+
+/// 代码字面量的目标语言
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "gui", derive(serde::Serialize, serde::Deserialize))]
+pub enum LiteralLanguage {
+    C,
+    Rust,
+}
+
+/// 按值的宽度(8/16/32/64位)生成可直接粘贴的无符号整数字面量，radix为2、10或16
+///
+/// Rust使用u8~u64后缀，2进制每4位插入下划线；C的32位以内用U后缀，64位用ULL
+///
+/// ```
+/// use number_conversion::convert::{code_literal, LiteralLanguage};
+/// assert_eq!(code_literal(255, 16, LiteralLanguage::Rust), "0xffu8");
+/// assert_eq!(code_literal(255, 2, LiteralLanguage::Rust), "0b1111_1111u8");
+/// assert_eq!(code_literal(256, 10, LiteralLanguage::Rust), "256u16");
+/// assert_eq!(code_literal(255, 16, LiteralLanguage::C), "0xffU");
+/// assert_eq!(code_literal(1 << 40, 16, LiteralLanguage::C), "0x10000000000ULL");
+/// ```
+pub fn code_literal(value: u64, radix: u32, language: LiteralLanguage) -> String {
+    let bits = match significant_bits(value) {
+        0..=8 => 8,
+        9..=16 => 16,
+        17..=32 => 32,
+        _ => 64,
+    };
+    let digits = to_radix(value, radix);
+    let prefix = match radix {
+        2 => "0b",
+        16 => "0x",
+        _ => "",
+    };
+    match language {
+        LiteralLanguage::Rust => {
+            let digits = if radix == 2 {
+                group_digits(&digits, 4).replace(' ', "_")
+            } else {
+                digits
+            };
+            format!("{}{}u{}", prefix, digits, bits)
+        }
+        LiteralLanguage::C => {
+            let suffix = if bits == 64 { "ULL" } else { "U" };
+            format!("{}{}{}", prefix, digits, suffix)
+        }
+    }
+}
+
+/// 为1的位的位置，从高位到低位排列，0返回空列表
+///
+/// ```
+/// use number_conversion::convert::bit_weights;
+/// assert_eq!(bit_weights(13), vec![3, 2, 0]);
+/// assert_eq!(bit_weights(1 << 63), vec![63]);
+/// assert!(bit_weights(0).is_empty());
+/// ```
+pub fn bit_weights(value: u64) -> Vec<u32> {
+    (0..64).rev().filter(|bit| value >> bit & 1 == 1).collect()
+}
+
+/// 2进制字符串转10进制字符串
+///
+/// ```
+/// use number_conversion::convert::binary_to_decimal;
+/// assert_eq!(binary_to_decimal("1010").unwrap(), "10");
+/// ```
+pub fn binary_to_decimal(input: &str) -> Result<String, ParseIntError> {
+    parse_radix(input, 2).map(|value| to_radix(value, 10))
+}
+
+/// 16进制字符串按字节解析，长度为奇数或含非16进制字符时返回None
+///
+/// ```
+/// use number_conversion::convert::hex_to_bytes;
+/// assert_eq!(hex_to_bytes("48_65"), Some(vec![0x48, 0x65]));
+/// assert_eq!(hex_to_bytes("486"), None);
+/// ```
+pub fn hex_to_bytes(input: &str) -> Option<Vec<u8>> {
+    let hex: Vec<char> = input.chars().filter(|c| *c != '_').collect();
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    hex.chunks(2)
+        .map(|pair| {
+            let high = pair[0].to_digit(16)?;
+            let low = pair[1].to_digit(16)?;
+            Some((high * 16 + low) as u8)
+        })
+        .collect()
+}
+
+/// 从粘贴的代码或十六进制转储中提取字节，支持C数组、"\x"转义、逗号和换行分隔
+///
+/// 每个片段可带0x前缀，单个字符的片段(如C数组中的0xa)视为一个字节，
+/// 连续的16进制串按两个字符一个字节解析
+///
+/// ```
+/// use number_conversion::convert::parse_hex_bytes;
+/// assert_eq!(parse_hex_bytes("{0x48, 0x65, 0x6C};"), Some(vec![0x48, 0x65, 0x6C]));
+/// assert_eq!(parse_hex_bytes("\\x48\\x65"), Some(vec![0x48, 0x65]));
+/// assert_eq!(parse_hex_bytes("0xa,\n0xff"), Some(vec![0x0A, 0xFF]));
+/// assert_eq!(parse_hex_bytes("DE AD be_ef"), Some(vec![0xDE, 0xAD, 0xBE, 0xEF]));
+/// assert_eq!(parse_hex_bytes("0x486"), None);
+/// assert_eq!(parse_hex_bytes("{0xzz}"), None);
+/// ```
+pub fn parse_hex_bytes(input: &str) -> Option<Vec<u8>> {
+    let cleaned: String = input
+        .replace("\\x", " ")
+        .chars()
+        .map(|c| if "{}[](),;".contains(c) { ' ' } else { c })
+        .collect();
+    let mut bytes = Vec::new();
+    for token in cleaned.split_whitespace() {
+        let token = strip_radix_prefix(token, 16);
+        if token.len() == 1 {
+            bytes.extend(hex_to_bytes(&format!("0{}", token))?);
+        } else {
+            bytes.extend(hex_to_bytes(token)?);
+        }
+    }
+    Some(bytes)
+}
+
+/// f32的IEEE 754位模式
+///
+/// ```
+/// use number_conversion::convert::f32_bits;
+/// assert_eq!(f32_bits(1.0), 0x3f80_0000);
+/// ```
+pub fn f32_bits(value: f32) -> u32 {
+    value.to_bits()
+}
+
+/// 浮点数按IEEE 754的分类
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FloatCategory {
+    Zero,
+    /// 非规格化数，指数全0且尾数不为0
+    Subnormal,
+    Normal,
+    Infinity,
+    Nan,
+}
+
+/// f32的符号位、8位指数和23位尾数
+///
+/// 去偏置指数、分类和数值都由这三个字段推出，所以做成方法(unbiased_exponent/category/compose)
+/// 而不是字段：位域编辑器会单独修改某个字段，冗余字段会与之不一致
+///
+/// ```
+/// use number_conversion::convert::F32Parts;
+/// let parts = F32Parts::new(-2.0);
+/// assert_eq!(parts, F32Parts { sign: true, exponent: 128, mantissa: 0 });
+/// assert_eq!(parts.compose(), -2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct F32Parts {
+    pub sign: bool,
+    pub exponent: u8,
+    pub mantissa: u32,
+}
+
+impl F32Parts {
+    pub fn new(value: f32) -> F32Parts {
+        let bits = value.to_bits();
+        F32Parts {
+            sign: bits >> 31 == 1,
+            exponent: (bits >> 23) as u8,
+            mantissa: bits & 0x7F_FFFF,
+        }
+    }
+    pub fn compose(&self) -> f32 {
+        compose_f32(self.sign, self.exponent, self.mantissa)
+    }
+    /// 去掉偏置127后的指数，零和非规格化数固定为-126，无穷大和NaN没有指数值
+    ///
+    /// ```
+    /// use number_conversion::convert::{hex_to_f32, F32Parts};
+    /// assert_eq!(F32Parts::new(hex_to_f32("3F800000").unwrap()).unbiased_exponent(), Some(0));
+    /// assert_eq!(F32Parts::new(0.5).unbiased_exponent(), Some(-1));
+    /// assert_eq!(F32Parts::new(f32::from_bits(1)).unbiased_exponent(), Some(-126));
+    /// assert_eq!(F32Parts::new(f32::INFINITY).unbiased_exponent(), None);
+    /// ```
+    pub fn unbiased_exponent(&self) -> Option<i32> {
+        match self.exponent {
+            0 => Some(-126),
+            255 => None,
+            exponent => Some(exponent as i32 - 127),
+        }
+    }
+    /// 按指数和尾数分类，与符号位无关
+    ///
+    /// ```
+    /// use number_conversion::convert::{F32Parts, FloatCategory};
+    /// assert_eq!(F32Parts::new(-0.0).category(), FloatCategory::Zero);
+    /// assert_eq!(F32Parts::new(f32::from_bits(1)).category(), FloatCategory::Subnormal);
+    /// assert_eq!(F32Parts::new(1.5).category(), FloatCategory::Normal);
+    /// assert_eq!(F32Parts::new(f32::NEG_INFINITY).category(), FloatCategory::Infinity);
+    /// assert_eq!(F32Parts::new(f32::NAN).category(), FloatCategory::Nan);
+    /// ```
+    pub fn category(&self) -> FloatCategory {
+        match (self.exponent, self.mantissa) {
+            (0, 0) => FloatCategory::Zero,
+            (0, _) => FloatCategory::Subnormal,
+            (255, 0) => FloatCategory::Infinity,
+            (255, _) => FloatCategory::Nan,
+            _ => FloatCategory::Normal,
+        }
+    }
+}
+
+/// 解析f32文本并分解为符号位、指数和尾数，忽略下划线，支持科学计数法
+///
+/// ```
+/// use number_conversion::convert::{decompose_f32, F32Parts, FloatCategory};
+/// let parts = decompose_f32("-1_0.5").unwrap();
+/// assert_eq!(parts, F32Parts { sign: true, exponent: 130, mantissa: 0x28_0000 });
+/// assert_eq!(parts.unbiased_exponent(), Some(3));
+/// assert_eq!(parts.category(), FloatCategory::Normal);
+/// assert_eq!(parts.compose(), -10.5);
+/// assert_eq!(decompose_f32("1e-45").unwrap().category(), FloatCategory::Subnormal);
+/// assert!(decompose_f32("1.2.3").is_err());
+/// ```
+pub fn decompose_f32(input: &str) -> Result<F32Parts, ParseFloatError> {
+    input.trim().replace('_', "").parse::<f32>().map(F32Parts::new)
+}
+
+/// 由符号位、指数和尾数组成f32，尾数超出23位的部分被丢弃
+///
+/// 指数全0时为零或非规格化数，全1时尾数为0是无穷大、否则是NaN
+///
+/// ```
+/// use number_conversion::convert::compose_f32;
+/// assert_eq!(compose_f32(false, 127, 0), 1.0);
+/// assert_eq!(compose_f32(true, 255, 0), f32::NEG_INFINITY);
+/// assert!(compose_f32(false, 255, 1).is_nan());
+/// assert_eq!(compose_f32(false, 0, 1), f32::from_bits(1));
+/// ```
+pub fn compose_f32(sign: bool, exponent: u8, mantissa: u32) -> f32 {
+    f32::from_bits((sign as u32) << 31 | (exponent as u32) << 23 | (mantissa & 0x7F_FFFF))
+}
+
+/// 把10进制整数当作f32的32位位模式，负数按补码，超出32位时返回None
+///
+/// 与把整数作为数值编码不同：数值编码时5按5.0编码为40a00000，位重解释时编码就是00000005
+///
+/// ```
+/// use number_conversion::convert::{f32_to_hex, int_bits_to_f32};
+/// assert_eq!(f32_to_hex(5.0), "40a00000");
+/// assert_eq!(int_bits_to_f32("5").map(f32_to_hex), Some("00000005".to_string()));
+/// assert_eq!(int_bits_to_f32("1065353216"), Some(1.0));
+/// assert_eq!(int_bits_to_f32("-1").map(f32::to_bits), Some(0xffff_ffff));
+/// assert_eq!(int_bits_to_f32("4294967296"), None);
+/// assert_eq!(int_bits_to_f32("5.0"), None);
+/// ```
+pub fn int_bits_to_f32(input: &str) -> Option<f32> {
+    let input = input.trim().replace('_', "");
+    let bits = match input.parse::<u32>() {
+        Ok(bits) => bits,
+        Err(_) => input.parse::<i32>().ok()? as u32,
+    };
+    Some(f32::from_bits(bits))
+}
+
+/// f32编码为8位16进制字符串(小写)
+///
+/// ```
+/// use number_conversion::convert::f32_to_hex;
+/// assert_eq!(f32_to_hex(1.0), "3f800000");
+/// ```
+pub fn f32_to_hex(value: f32) -> String {
+    format!("{:08x}", f32_bits(value))
+}
+
+/// 8位以内的16进制编码解析为f32
+///
+/// ```
+/// use number_conversion::convert::hex_to_f32;
+/// assert_eq!(hex_to_f32("3F80_0000"), Ok(1.0));
+/// assert_eq!(hex_to_f32("7FC00001").unwrap().to_bits(), 0x7FC0_0001);
+/// ```
+pub fn hex_to_f32(input: &str) -> Result<f32, ParseIntError> {
+    u32::from_str_radix(&input.replace('_', ""), 16).map(f32::from_bits)
+}
+
+/// 浮点数结果的显示方式
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "gui", derive(serde::Serialize, serde::Deserialize))]
+pub enum FloatFormat {
+    /// 能还原出原值的最短表示
+    Auto,
+    /// 固定的小数位数
+    Fixed,
+    /// 固定的有效数字位数，数量级过大或过小时使用科学计数法
+    Significant,
+}
+
+/// 按显示方式格式化浮点数，digits为小数位数或有效数字位数，Auto时不使用；NaN和无穷大原样输出
+///
+/// ```
+/// use number_conversion::convert::{format_float, FloatFormat};
+/// assert_eq!(format_float(3.14159265, FloatFormat::Significant, 4), "3.142");
+/// assert_eq!(format_float(0.000123456, FloatFormat::Significant, 4), "0.0001235");
+/// assert_eq!(format_float(9.9996, FloatFormat::Significant, 4), "10.00");
+/// assert_eq!(format_float(123456.0, FloatFormat::Significant, 4), "1.235e5");
+/// assert_eq!(format_float(2.5, FloatFormat::Fixed, 3), "2.500");
+/// assert_eq!(format_float(0.1, FloatFormat::Auto, 3), "0.1");
+/// assert_eq!(format_float(f64::INFINITY, FloatFormat::Fixed, 3), "inf");
+/// ```
+pub fn format_float(value: f64, format: FloatFormat, digits: usize) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    match format {
+        FloatFormat::Auto => value.to_string(),
+        FloatFormat::Fixed => format!("{:.*}", digits, value),
+        FloatFormat::Significant => {
+            let digits = digits.max(1);
+            //先按科学计数法舍入，得到舍入后的数量级
+            let scientific = format!("{:.*e}", digits - 1, value);
+            let exponent: i32 = scientific.split_once('e').and_then(|(_, e)| e.parse().ok()).unwrap_or(0);
+            if exponent < -4 || exponent >= digits as i32 {
+                scientific
+            } else {
+                format!("{:.*}", (digits as i32 - 1 - exponent) as usize, value)
+            }
+        }
+    }
+}
+
+/// 从右往左每size个字符插入一个空格，用于长数字的分组预览
+///
+/// ```
+/// use number_conversion::convert::group_digits;
+/// assert_eq!(group_digits("DEADBEEF", 2), "DE AD BE EF");
+/// assert_eq!(group_digits("101101", 4), "10 1101");
+/// ```
+pub fn group_digits(digits: &str, size: usize) -> String {
+    separate_digits(digits, size, ' ')
+}
+
+/// 整数部分从右往左每size位插入分隔符，负号和小数部分保持不变，size为0时不分组
+///
+/// ```
+/// use number_conversion::convert::separate_digits;
+/// assert_eq!(separate_digits("11110000", 4, '_'), "1111_0000");
+/// assert_eq!(separate_digits("17777", 3, '_'), "17_777");
+/// assert_eq!(separate_digits("-1234567.125", 3, ','), "-1,234,567.125");
+/// assert_eq!(separate_digits("deadbeef", 2, ' '), "de ad be ef");
+/// assert_eq!(separate_digits("ffff", 0, '_'), "ffff");
+/// ```
+pub fn separate_digits(digits: &str, size: usize, separator: char) -> String {
+    let (sign, unsigned) = match digits.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", digits),
+    };
+    let (integer, fraction) = unsigned.split_at(unsigned.find('.').unwrap_or(unsigned.len()));
+    let len = integer.chars().count();
+    let mut result = String::with_capacity(digits.len() + len / size.max(1));
+    result.push_str(sign);
+    for (i, c) in integer.chars().enumerate() {
+        if size > 0 && i > 0 && (len - i) % size == 0 {
+            result.push(separator);
+        }
+        result.push(c);
+    }
+    result.push_str(fraction);
+    result
+}
+
+/// 按group_digits分组后每行放groups_per_line组，行从右往左排满，第一行可能不满
+///
+/// ```
+/// use number_conversion::convert::wrap_digits;
+/// let bits = "1".repeat(32) + &"0".repeat(32);
+/// assert_eq!(
+///     wrap_digits(&bits, 8, 4),
+///     "11111111 11111111 11111111 11111111\n00000000 00000000 00000000 00000000"
+/// );
+/// assert_eq!(wrap_digits("1101101", 4, 4), "110 1101");
+/// ```
+pub fn wrap_digits(digits: &str, size: usize, groups_per_line: usize) -> String {
+    let grouped = group_digits(digits, size);
+    let groups: Vec<&str> = grouped.split(' ').collect();
+    if groups_per_line == 0 {
+        return grouped;
+    }
+    let first = groups.len() % groups_per_line;
+    let mut lines: Vec<String> = Vec::new();
+    if first > 0 {
+        lines.push(groups[..first].join(" "));
+    }
+    lines.extend(groups[first..].chunks(groups_per_line).map(|line| line.join(" ")));
+    lines.join("\n")
+}
+
+/// 用前导零把2、8或16进制数字补齐到bits位宽，比位宽更长的值不截断
+///
+/// ```
+/// use number_conversion::convert::pad_digits;
+/// assert_eq!(pad_digits("101", 2, 8), "00000101");
+/// assert_eq!(pad_digits("ff", 16, 32), "000000ff");
+/// assert_eq!(pad_digits("1ff", 16, 8), "1ff");
+/// ```
+pub fn pad_digits(digits: &str, radix: u32, bits: u32) -> String {
+    let bits_per_digit = match radix {
+        2 => 1,
+        8 => 3,
+        16 => 4,
+        _ => return digits.to_string(),
+    };
+    let width = bits.div_ceil(bits_per_digit) as usize;
+    format!("{:0>width$}", digits)
+}
+
+/// 把数值截断到bits位宽，返回截断后的值和是否有位被丢弃，位宽为64及以上时不截断
+///
+/// ```
+/// use number_conversion::convert::mask_to_width;
+/// assert_eq!(mask_to_width(300, 8), (44, true));
+/// assert_eq!(mask_to_width(255, 8), (255, false));
+/// assert_eq!(mask_to_width(u64::MAX, 64), (u64::MAX, false));
+/// ```
+pub fn mask_to_width(value: u64, bits: u32) -> (u64, bool) {
+    if bits >= 64 {
+        return (value, false);
+    }
+    let masked = value & ((1 << bits) - 1);
+    (masked, masked != value)
+}
+
+/// 有符号数按bits位宽取补码，返回位模式和是否超出该位宽的表示范围
+///
+/// 负数的范围为-2^(bits-1)及以上，非负数允许到无符号最大值；位宽按1~64位处理
+///
+/// ```
+/// use number_conversion::convert::twos_complement;
+/// assert_eq!(twos_complement(-1, 8), (0xff, false));
+/// assert_eq!(twos_complement(-128, 8), (0x80, false));
+/// assert_eq!(twos_complement(-129, 8), (0x7f, true));
+/// assert_eq!(twos_complement(300, 8), (44, true));
+/// assert_eq!(twos_complement(-1, 64), (u64::MAX, false));
+/// // 位宽为0时按1位处理
+/// assert_eq!(twos_complement(-1, 0), (1, false));
+/// assert_eq!(twos_complement(-2, 0), (0, true));
+/// assert_eq!(twos_complement(1, 0), (1, false));
+/// ```
+pub fn twos_complement(value: i64, bits: u32) -> (u64, bool) {
+    let bits = bits.clamp(1, 64);
+    let (masked, truncated) = mask_to_width(value as u64, bits);
+    if value >= 0 {
+        return (masked, truncated);
+    }
+    (masked, (value as i128) < -(1i128 << (bits - 1)))
+}
+
+/// 把bits位宽的位模式按补码解释为有符号数，是twos_complement的逆运算，高于位宽的位被忽略
+///
+/// ```
+/// use number_conversion::convert::sign_extend;
+/// assert_eq!(sign_extend(0xfff6, 16), -10);
+/// assert_eq!(sign_extend(0x7ff6, 16), 0x7ff6);
+/// assert_eq!(sign_extend(0xffff_fff6, 32), -10);
+/// assert_eq!(sign_extend(0x1ff, 8), -1);
+/// assert_eq!(sign_extend(u64::MAX, 64), -1);
+/// ```
+pub fn sign_extend(value: u64, bits: u32) -> i64 {
+    let bits = bits.clamp(1, 64);
+    let shift = 64 - bits;
+    ((value << shift) as i64) >> shift
+}
+
+/// 把8位以内的16进制位模式同时解释为u32、i32(补码)和f32，长度超出或含非法字符时返回None
+///
+/// ```
+/// use number_conversion::convert::multi_interpret_32;
+/// let (unsigned, signed, float) = multi_interpret_32("FFFFFFFF").unwrap();
+/// assert_eq!(unsigned, 4294967295);
+/// assert_eq!(signed, -1);
+/// assert!(float.is_nan());
+/// assert_eq!(multi_interpret_32("0x3F80_0000"), Some((0x3F80_0000, 0x3F80_0000, 1.0)));
+/// assert_eq!(multi_interpret_32("123456789"), None);
+/// ```
+pub fn multi_interpret_32(hex: &str) -> Option<(u32, i32, f32)> {
+    let hex = hex.replace('_', "");
+    let digits = strip_radix_prefix(&hex, 16);
+    if digits.is_empty() || digits.len() > 8 {
+        return None;
+    }
+    let bits = u32::from_str_radix(digits, 16).ok()?;
+    Some((bits, bits as i32, f32::from_bits(bits)))
+}
+
+/// 把16位以内的16进制位模式同时解释为u64、i64(补码)和f64，长度超出或含非法字符时返回None
+///
+/// ```
+/// use number_conversion::convert::multi_interpret_64;
+/// assert_eq!(multi_interpret_64("3FF0000000000000"), Some((0x3FF0_0000_0000_0000, 0x3FF0_0000_0000_0000, 1.0)));
+/// let (unsigned, signed, float) = multi_interpret_64("FFFF_FFFF_FFFF_FFFF").unwrap();
+/// assert_eq!((unsigned, signed), (u64::MAX, -1));
+/// assert!(float.is_nan());
+/// assert_eq!(multi_interpret_64("1_0000_0000_0000_0000"), None);
+/// ```
+pub fn multi_interpret_64(hex: &str) -> Option<(u64, i64, f64)> {
+    let hex = hex.replace('_', "");
+    let digits = strip_radix_prefix(&hex, 16);
+    if digits.is_empty() || digits.len() > 16 {
+        return None;
+    }
+    let bits = u64::from_str_radix(digits, 16).ok()?;
+    Some((bits, bits as i64, f64::from_bits(bits)))
+}
+
+/// 反转半字节顺序
+///
+/// ```
+/// use number_conversion::convert::reverse_nibbles;
+/// assert_eq!(reverse_nibbles("DEADBEEF"), "FEEBDAED");
+/// ```
+pub fn reverse_nibbles(hex: &str) -> String {
+    hex.chars().rev().collect()
+}
+
+/// 反转字节顺序，长度为奇数时返回None
+///
+/// ```
+/// use number_conversion::convert::reverse_bytes;
+/// assert_eq!(reverse_bytes("DEADBEEF").as_deref(), Some("EFBEADDE"));
+/// assert_eq!(reverse_bytes("ABC"), None);
+/// ```
+pub fn reverse_bytes(hex: &str) -> Option<String> {
+    let chars: Vec<char> = hex.chars().collect();
+    if !chars.len().is_multiple_of(2) {
+        return None;
+    }
+    Some(chars.chunks(2).rev().flatten().collect())
+}
+
+/// 数值按bits位宽(向上取整到整字节)的大端序和小端序16进制(小写)，超出位宽的高位被截断
+///
+/// ```
+/// use number_conversion::convert::byte_orders;
+/// assert_eq!(byte_orders(258, 16), ("0102".to_string(), "0201".to_string()));
+/// assert_eq!(byte_orders(0xDEADBEEF, 32), ("deadbeef".to_string(), "efbeadde".to_string()));
+/// assert_eq!(byte_orders(0x1FF, 12), ("01ff".to_string(), "ff01".to_string()));
+/// assert_eq!(byte_orders(0x1234, 8), ("34".to_string(), "34".to_string()));
+/// ```
+pub fn byte_orders(value: u64, bits: u32) -> (String, String) {
+    let bits = bits.div_ceil(8).clamp(1, 8) * 8;
+    let (value, _) = mask_to_width(value, bits);
+    let big_endian = pad_digits(&to_radix(value, 16), 16, bits);
+    let little_endian = reverse_bytes(&big_endian).unwrap_or_default();
+    (big_endian, little_endian)
+}
+
+/// 16进制字切分失败的原因
+#[derive(Debug, PartialEq)]
+pub enum HexWordError {
+    /// 不是16进制数字的字符
+    InvalidChar(char),
+    /// 末尾剩余的位数不足一个字
+    Incomplete(usize),
+}
+
+/// 把连续的16进制数据按word_digits位切分成字，little_endian为true时每个字按字节反转为大端序
+///
+/// 空白、逗号、下划线和每段的0x前缀被忽略，每个字单独返回结果，便于逐个报告错误
+///
+/// ```
+/// use number_conversion::convert::{multi_interpret_32, split_hex_words, HexWordError};
+/// let words = split_hex_words("0000803f 00000040", 8, true);
+/// assert_eq!(words, vec![Ok("3f800000".to_string()), Ok("40000000".to_string())]);
+/// let floats: Vec<f32> = words.iter().flatten().filter_map(|w| multi_interpret_32(w)).map(|(_, _, f)| f).collect();
+/// assert_eq!(floats, vec![1.0, 2.0]);
+/// assert_eq!(
+///     split_hex_words("0x3f800000, 4000zz00, 1234", 8, false),
+///     vec![Ok("3f800000".to_string()), Err(HexWordError::InvalidChar('z')), Err(HexWordError::Incomplete(4))]
+/// );
+/// ```
+pub fn split_hex_words(input: &str, word_digits: usize, little_endian: bool) -> Vec<Result<String, HexWordError>> {
+    let digits: Vec<char> = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .flat_map(|token| strip_radix_prefix(token, 16).chars())
+        .filter(|&c| c != '_')
+        .collect();
+    digits
+        .chunks(word_digits.max(1))
+        .map(|chunk| {
+            if let Some(&c) = chunk.iter().find(|c| !c.is_ascii_hexdigit()) {
+                return Err(HexWordError::InvalidChar(c));
+            }
+            if chunk.len() < word_digits {
+                return Err(HexWordError::Incomplete(chunk.len()));
+            }
+            let word: String = chunk.iter().collect();
+            match little_endian {
+                //word_digits为偶数，reverse_bytes不会失败
+                true => Ok(reverse_bytes(&word).unwrap_or(word)),
+                false => Ok(word),
+            }
+        })
+        .collect()
+}
+
+/// 把bits位(32或64)浮点数的16进制编码补齐前导零后按字节反转，用于字节序相反的数据，超出位宽时返回None
+///
+/// ```
+/// use number_conversion::convert::{hex_to_f32, swap_float_bytes};
+/// assert_eq!(swap_float_bytes("0000803f", 32).as_deref(), Some("3f800000"));
+/// assert_eq!(hex_to_f32(&swap_float_bytes("0000_803F", 32).unwrap()), Ok(1.0));
+/// assert_eq!(swap_float_bytes("803f", 32).as_deref(), Some("3f800000"));
+/// let swapped = swap_float_bytes("000000000000f03f", 64).unwrap();
+/// assert_eq!(f64::from_bits(u64::from_str_radix(&swapped, 16).unwrap()), 1.0);
+/// assert_eq!(swap_float_bytes("123456789", 32), None);
+/// ```
+pub fn swap_float_bytes(hex: &str, bits: u32) -> Option<String> {
+    let digits = pad_digits(&hex.replace('_', ""), 16, bits);
+    if digits.len() * 4 > bits as usize {
+        return None;
+    }
+    reverse_bytes(&digits)
+}
+
+/// 每行16字节的hex dump，包含偏移、16进制和可打印ASCII三列
+///
+/// ```
+/// use number_conversion::convert::hex_dump;
+/// assert_eq!(
+///     hex_dump(b"Hi!\n"),
+///     "00000000  48 69 21 0a                                      |Hi!.|"
+/// );
+/// ```
+pub fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' })
+                .collect();
+            format!("{:08x}  {:<47}  |{}|", row * 16, hex.join(" "), ascii)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        //包括NaN在内的所有位模式都能原样还原，按位比较
+        #[test]
+        fn f32_hex_round_trip(bits in any::<u32>()) {
+            let hex = f32_to_hex(f32::from_bits(bits));
+            prop_assert_eq!(hex.len(), 8);
+            prop_assert_eq!(hex_to_f32(&hex).unwrap().to_bits(), bits);
+        }
+
+        #[test]
+        fn radix_round_trip(value in any::<u64>(), radix in prop::sample::select(vec![2u32, 8, 10, 16])) {
+            let digits = to_radix(value, radix);
+            prop_assert_eq!(parse_radix(&digits, radix), Ok(value));
+            //分组后的结果仍能解析
+            prop_assert_eq!(parse_radix(&separate_digits(&digits, 4, '_'), radix), Ok(value));
+        }
+
+        //带前缀的结果能被智能输入识别为原来的进制
+        #[test]
+        fn prefixed_radix_is_detected(value in any::<u64>(), radix in prop::sample::select(vec![2u32, 8, 16])) {
+            let literal = with_radix_prefix(&to_radix(value, radix), radix);
+            let (detected, digits) = detect_number_format(&literal).unwrap();
+            prop_assert_eq!(detected, radix);
+            prop_assert_eq!(parse_radix(&digits, radix), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,411 @@
+//! 统一的文本转换接口，实现Converter并注册后即可由界面按名称使用
+
+use crate::convert::parse_hex_bytes;
+use crate::encoding::*;
+use crate::unicode::*;
+
+/// 转换失败的原因，message为中文说明，界面以它为键查找译文，其中的"{}"替换为detail
+///
+/// ```
+/// use number_conversion::converter::ConvertError;
+/// let error = ConvertError::with_detail("字符'{}'不在Base58字母表中", '0');
+/// assert_eq!(error.render(error.message), "字符'0'不在Base58字母表中");
+/// assert_eq!(ConvertError::new("请输入文本").render("Please enter text"), "Please enter text");
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct ConvertError {
+    pub message: &'static str,
+    pub detail: Option<String>,
+}
+
+impl ConvertError {
+    pub fn new(message: &'static str) -> ConvertError {
+        ConvertError { message, detail: None }
+    }
+    pub fn with_detail(message: &'static str, detail: impl ToString) -> ConvertError {
+        ConvertError { message, detail: Some(detail.to_string()) }
+    }
+    /// 把message(或它的译文)中的"{}"替换为detail
+    pub fn render(&self, message: &str) -> String {
+        match &self.detail {
+            Some(detail) => message.replace("{}", detail),
+            None => message.to_string(),
+        }
+    }
+}
+
+/// 与界面设置有关的转换选项，默认为小写16进制、大端序、换行原样编码
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ConvertOptions {
+    pub hex_uppercase: bool,
+    pub utf16_little_endian: bool,
+    /// 编码前把"\r\n"统一为"\n"
+    pub normalize_newlines: bool,
+}
+
+/// 把一段输入文本转换为结果文本的转换器
+///
+/// ```
+/// use number_conversion::converter::{ConvertError, Converter};
+/// struct Reverse;
+/// impl Converter for Reverse {
+///     fn name(&self) -> &'static str { "反转" }
+///     fn hint(&self) -> &'static str { "逐字符反转" }
+///     fn output_label(&self) -> &'static str { "结果:" }
+///     fn convert(&self, input: &str) -> Result<String, ConvertError> {
+///         Ok(input.chars().rev().collect())
+///     }
+/// }
+/// assert_eq!(Reverse.convert("abc"), Ok("cba".to_string()));
+/// assert_eq!(Reverse.empty_message(), "请输入数值");
+/// ```
+pub trait Converter {
+    /// 名称，同时是注册表中的键和界面上的标题
+    fn name(&self) -> &'static str;
+    /// 输入格式的说明
+    fn hint(&self) -> &'static str;
+    /// 结果前的标签
+    fn output_label(&self) -> &'static str;
+    /// 输入为空时的提示
+    fn empty_message(&self) -> &'static str {
+        "请输入数值"
+    }
+    /// 反向转换器的名称，界面据此放置把结果填入对方输入框的按钮
+    fn inverse(&self) -> Option<&'static str> {
+        None
+    }
+    /// 输入是数值时的进制，屏幕小键盘据此限制可用的按键
+    fn input_radix(&self) -> Option<u32> {
+        None
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError>;
+    /// 按界面设置转换，结果与设置无关的转换器不需要实现
+    fn convert_with(&self, input: &str, _options: &ConvertOptions) -> Result<String, ConvertError> {
+        self.convert(input)
+    }
+}
+
+/// 按注册顺序保存的转换器，界面按注册表显示各转换面板
+///
+/// 作为库使用时可以在内置转换器之外注册自己的转换器
+///
+/// ```
+/// use number_conversion::converter::{ConvertError, Converter, Registry};
+/// let mut registry = Registry::with_builtins();
+/// let encode = registry.get("文本转Quoted-Printable").unwrap();
+/// assert_eq!(encode.convert("a=b"), Ok("a=3Db".to_string()));
+/// assert!(registry.get("不存在").is_none());
+///
+/// struct Upper;
+/// impl Converter for Upper {
+///     fn name(&self) -> &'static str { "大写" }
+///     fn hint(&self) -> &'static str { "" }
+///     fn output_label(&self) -> &'static str { "结果:" }
+///     fn convert(&self, input: &str) -> Result<String, ConvertError> {
+///         Ok(input.to_uppercase())
+///     }
+/// }
+/// registry.register(Box::new(Upper));
+/// assert_eq!(registry.names().last(), Some("大写"));
+/// assert_eq!(registry.names().count(), 9);
+/// registry.register(Box::new(Upper));
+/// assert_eq!(registry.names().count(), 9);
+/// ```
+#[derive(Default)]
+pub struct Registry {
+    converters: Vec<Box<dyn Converter>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+    /// 包含内置的Quoted-Printable和Punycode转换器
+    pub fn with_builtins() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(Box::new(Utf16Encode));
+        registry.register(Box::new(Utf16Decode));
+        registry.register(Box::new(QpEncode));
+        registry.register(Box::new(QpDecode));
+        registry.register(Box::new(PunycodeEncode));
+        registry.register(Box::new(PunycodeDecode));
+        registry.register(Box::new(Base58Encode));
+        registry.register(Box::new(Base58Decode));
+        registry
+    }
+    /// 注册转换器，同名的转换器被替换
+    pub fn register(&mut self, converter: Box<dyn Converter>) {
+        match self.converters.iter_mut().find(|c| c.name() == converter.name()) {
+            Some(existing) => *existing = converter,
+            None => self.converters.push(converter),
+        }
+    }
+    pub fn get(&self, name: &str) -> Option<&dyn Converter> {
+        self.iter().find(|c| c.name() == name)
+    }
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Converter> {
+        self.converters.iter().map(|c| c.as_ref())
+    }
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.iter().map(|c| c.name())
+    }
+}
+
+/// 文本编码为UTF-16，字节序和换行处理由选项决定，不添加BOM
+pub struct Utf16Encode;
+
+impl Converter for Utf16Encode {
+    fn name(&self) -> &'static str {
+        "文本转UTF-16"
+    }
+    fn hint(&self) -> &'static str {
+        "字节序在设置中选择，不添加BOM"
+    }
+    fn output_label(&self) -> &'static str {
+        "UTF-16:"
+    }
+    fn empty_message(&self) -> &'static str {
+        "请输入文本"
+    }
+    fn inverse(&self) -> Option<&'static str> {
+        Some("UTF-16转文本")
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError> {
+        self.convert_with(input, &ConvertOptions::default())
+    }
+    fn convert_with(&self, input: &str, options: &ConvertOptions) -> Result<String, ConvertError> {
+        let text = if options.normalize_newlines {
+            normalize_newlines(input)
+        } else {
+            input.to_string()
+        };
+        Ok(utf16_encode(&text, options.utf16_little_endian))
+    }
+}
+
+/// UTF-16的16进制码元解码为文本
+pub struct Utf16Decode;
+
+impl Converter for Utf16Decode {
+    fn name(&self) -> &'static str {
+        "UTF-16转文本"
+    }
+    fn hint(&self) -> &'static str {
+        "可输入空格或下划线做视觉分割，BOM不做特殊处理"
+    }
+    fn output_label(&self) -> &'static str {
+        "文本:"
+    }
+    fn inverse(&self) -> Option<&'static str> {
+        Some("文本转UTF-16")
+    }
+    fn input_radix(&self) -> Option<u32> {
+        Some(16)
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError> {
+        self.convert_with(input, &ConvertOptions::default())
+    }
+    fn convert_with(&self, input: &str, options: &ConvertOptions) -> Result<String, ConvertError> {
+        utf16_decode(input, options.utf16_little_endian).map_err(|e| match e {
+            Utf16Error::InvalidHex => ConvertError::new("请输入16进制字符"),
+            Utf16Error::OddLength => ConvertError::new("长度需为4个16进制字符的倍数"),
+            Utf16Error::LoneSurrogate => ConvertError::new("存在未配对的代理项"),
+        })
+    }
+}
+
+/// 文本编码为Quoted-Printable
+pub struct QpEncode;
+
+impl Converter for QpEncode {
+    fn name(&self) -> &'static str {
+        "文本转Quoted-Printable"
+    }
+    fn hint(&self) -> &'static str {
+        "每行超过76个字符时插入软换行"
+    }
+    fn output_label(&self) -> &'static str {
+        "Quoted-Printable:"
+    }
+    fn empty_message(&self) -> &'static str {
+        "请输入文本"
+    }
+    fn inverse(&self) -> Option<&'static str> {
+        Some("Quoted-Printable转文本")
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError> {
+        Ok(quoted_printable_encode(input))
+    }
+}
+
+/// Quoted-Printable解码为文本
+pub struct QpDecode;
+
+impl Converter for QpDecode {
+    fn name(&self) -> &'static str {
+        "Quoted-Printable转文本"
+    }
+    fn hint(&self) -> &'static str {
+        "支持\"=\\r\\n\"软换行"
+    }
+    fn output_label(&self) -> &'static str {
+        "文本:"
+    }
+    fn inverse(&self) -> Option<&'static str> {
+        Some("文本转Quoted-Printable")
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError> {
+        quoted_printable_decode(input).map_err(|e| match e {
+            QpError::InvalidEscape => ConvertError::new("\"=\"后需要两个16进制字符"),
+            QpError::InvalidUtf8 => ConvertError::new("解码结果不是有效的UTF-8"),
+        })
+    }
+}
+
+/// 域名编码为Punycode
+pub struct PunycodeEncode;
+
+impl Converter for PunycodeEncode {
+    fn name(&self) -> &'static str {
+        "域名转Punycode"
+    }
+    fn hint(&self) -> &'static str {
+        "如münchen.de，非ASCII的标签转换为xn--形式"
+    }
+    fn output_label(&self) -> &'static str {
+        "Punycode:"
+    }
+    fn empty_message(&self) -> &'static str {
+        "请输入域名"
+    }
+    fn inverse(&self) -> Option<&'static str> {
+        Some("Punycode转域名")
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError> {
+        domain_to_ascii(input).map_err(punycode_error)
+    }
+}
+
+/// Punycode解码为域名
+pub struct PunycodeDecode;
+
+impl Converter for PunycodeDecode {
+    fn name(&self) -> &'static str {
+        "Punycode转域名"
+    }
+    fn hint(&self) -> &'static str {
+        "如xn--mnchen-3ya.de，至少一个标签以xn--开头"
+    }
+    fn output_label(&self) -> &'static str {
+        "域名:"
+    }
+    fn empty_message(&self) -> &'static str {
+        "请输入域名"
+    }
+    fn inverse(&self) -> Option<&'static str> {
+        Some("域名转Punycode")
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError> {
+        domain_to_unicode(input).map_err(punycode_error)
+    }
+}
+
+/// 16进制字节编码为Base58
+pub struct Base58Encode;
+
+impl Converter for Base58Encode {
+    fn name(&self) -> &'static str {
+        "16进制转Base58"
+    }
+    fn hint(&self) -> &'static str {
+        "可粘贴C数组或\\x转义形式，开头的00字节编码为1"
+    }
+    fn output_label(&self) -> &'static str {
+        "Base58:"
+    }
+    fn inverse(&self) -> Option<&'static str> {
+        Some("Base58转16进制")
+    }
+    fn input_radix(&self) -> Option<u32> {
+        Some(16)
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError> {
+        parse_hex_bytes(input)
+            .map(|bytes| base58_encode(&bytes))
+            .ok_or(ConvertError::new("请输入偶数个16进制字符"))
+    }
+}
+
+/// Base58解码为16进制字节
+pub struct Base58Decode;
+
+impl Converter for Base58Decode {
+    fn name(&self) -> &'static str {
+        "Base58转16进制"
+    }
+    fn hint(&self) -> &'static str {
+        "比特币字母表，不含0、O、I、l"
+    }
+    fn output_label(&self) -> &'static str {
+        "16进制:"
+    }
+    fn empty_message(&self) -> &'static str {
+        "请输入Base58字符"
+    }
+    fn inverse(&self) -> Option<&'static str> {
+        Some("16进制转Base58")
+    }
+    fn convert(&self, input: &str) -> Result<String, ConvertError> {
+        self.convert_with(input, &ConvertOptions::default())
+    }
+    fn convert_with(&self, input: &str, options: &ConvertOptions) -> Result<String, ConvertError> {
+        let bytes = base58_decode(input).map_err(|Base58Error::InvalidChar(c)| ConvertError::with_detail("字符'{}'不在Base58字母表中", c))?;
+        let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+        Ok(if options.hex_uppercase { hex.to_uppercase() } else { hex })
+    }
+}
+
+//编码和解码共用的错误提示
+fn punycode_error(error: PunycodeError) -> ConvertError {
+    match error {
+        PunycodeError::MissingPrefix => ConvertError::new("没有以xn--开头的标签"),
+        PunycodeError::InvalidInput => ConvertError::new("不是有效的Punycode"),
+        PunycodeError::Overflow => ConvertError::new("数值溢出，域名过长"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn punycode_errors_are_distinct() {
+        assert_eq!(PunycodeDecode.convert("example.com"), Err(ConvertError::new("没有以xn--开头的标签")));
+        assert_eq!(PunycodeDecode.convert("xn--ab!c"), Err(ConvertError::new("不是有效的Punycode")));
+        assert_eq!(PunycodeDecode.convert("xn--99999999999"), Err(ConvertError::new("数值溢出，域名过长")));
+    }
+
+    #[test]
+    fn crlf_verbatim_and_normalized() {
+        let options = |normalize_newlines| ConvertOptions { normalize_newlines, ..ConvertOptions::default() };
+        assert_eq!(Utf16Encode.convert_with("a\r\nb", &options(false)), Ok("0061 000D 000A 0062".to_string()));
+        assert_eq!(Utf16Encode.convert_with("a\r\nb", &options(true)), Ok("0061 000A 0062".to_string()));
+    }
+
+    #[test]
+    fn utf16_follows_byte_order() {
+        let little_endian = ConvertOptions { utf16_little_endian: true, ..ConvertOptions::default() };
+        assert_eq!(Utf16Encode.convert_with("A", &little_endian), Ok("4100".to_string()));
+        assert_eq!(Utf16Decode.convert_with("4100", &little_endian), Ok("A".to_string()));
+        assert_eq!(Utf16Decode.convert("D83E"), Err(ConvertError::new("存在未配对的代理项")));
+    }
+
+    #[test]
+    fn base58_round_trip() {
+        assert_eq!(Base58Encode.convert("00 00 28 7f b4 cd"), Ok("11233QC4".to_string()));
+        let uppercase = ConvertOptions { hex_uppercase: true, ..ConvertOptions::default() };
+        assert_eq!(Base58Decode.convert_with("11233QC4", &uppercase), Ok("0000287FB4CD".to_string()));
+        assert_eq!(Base58Decode.convert("10"), Err(ConvertError::with_detail("字符'{}'不在Base58字母表中", '0')));
+        assert_eq!(Base58Encode.convert("abc"), Err(ConvertError::new("请输入偶数个16进制字符")));
+    }
+}
@@ -0,0 +1,37 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::converter::*;
+
+//任意Converter的通用面板，标题、提示和错误信息都来自转换器
+pub fn converter_panel(converter: &dyn Converter, data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut output = Err(ConvertError::new(""));
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t(converter.name())).color(Color32::BLUE)).on_hover_text(t(converter.hint()));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+
+        if data.input_data.is_empty() {
+            data.set_data_error(DataError::LenNull);
+            return;
+        }
+        output = converter.convert_with(&data.input_data, &settings.convert_options());
+        if output.is_err() {
+            data.set_data_error(DataError::FormatError);
+        }
+    });
+    ui.horizontal(|ui| {
+        match (data.get_data_error(), output) {
+            (DataError::LenNull, _) => ui.colored_label(Color32::RED, t(converter.empty_message())),
+            (_, Ok(text)) => {
+                    data.set_output_data(text);
+                    ui.add(Label::new(RichText::new(t(converter.output_label())).color(Color32::BLUE)));
+                    copyable(ui, &data.output_data)
+            }
+            (_, Err(error)) => ui.colored_label(Color32::RED, error.render(t(error.message))),
+        }
+    });
+}
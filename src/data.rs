@@ -1,3 +1,9 @@
+use crate::i18n::Language;
+use std::collections::VecDeque;
+
+//历史记录最多保留的条数
+const HISTORY_LEN: usize = 20;
+
 #[derive(PartialEq)]
 pub enum DataError {
     FormatError,
@@ -6,10 +12,31 @@ pub enum DataError {
     Nice,
 }
 
+impl DataError {
+    //状态栏中显示的简短状态，按变体和语言查表
+    pub fn localized(&self, language: Language) -> &'static str {
+        match (self, language) {
+            (DataError::FormatError, Language::Chinese) => "格式错误",
+            (DataError::FormatError, Language::English) => "Format error",
+            (DataError::LenNull, Language::Chinese) => "空",
+            (DataError::LenNull, Language::English) => "Empty",
+            (DataError::LenOver, Language::Chinese) => "超出范围",
+            (DataError::LenOver, Language::English) => "Out of range",
+            (DataError::Nice, Language::Chinese) => "有效",
+            (DataError::Nice, Language::English) => "Valid",
+        }
+    }
+}
+
 pub struct Data {
     pub input_data: String,
     pub output_data: String,
     pub data_error: DataError,
+    pub history: VecDeque<String>,
+    pub has_focus: bool,
+    //关闭实时转换时输入框编辑的草稿，以及最近一次提交的输入
+    pub draft_input: String,
+    pub committed_input: String,
 }
 
 impl Data {
@@ -18,6 +45,10 @@ impl Data {
             input_data: String::from(""),
             output_data: String::from(""),
             data_error: DataError::Nice,
+            history: VecDeque::new(),
+            has_focus: false,
+            draft_input: String::new(),
+            committed_input: String::new(),
         }
     }
     pub fn ref_input_data(&mut self) -> &mut String{
@@ -26,38 +57,6 @@ impl Data {
     pub fn set_output_data(&mut self, output_data: String) {
         self.output_data = output_data;
     }
-    pub fn get_output_data(&self) -> String {
-        let mut result = String::new();
-        let mut result_before_dot = String::new();
-        if let Some(dot_pos) = self.output_data.find('.') {
-            let (before_dot, after_dot) = self.output_data.split_at(dot_pos);
-            //反转小数点前部分的字符串，用于插入下划线
-            let reversed_before: String = before_dot.chars().rev().collect();
-            for (i, c) in reversed_before.chars().enumerate() {
-                if i > 0 && i % 4 == 0 {
-                    result_before_dot.push('_');
-                }
-                result_before_dot.push(c);
-            }
-            //反转回来
-            result_before_dot = result_before_dot.chars().rev().collect();
-            let result_after_dot = after_dot.to_string();
-            result = format!("{}{}", result_before_dot, result_after_dot);
-        } else {
-            //反转字符串，用于插入下划线
-            let reversed: String = self.output_data.chars().rev().collect();
-            for (i, c) in reversed.chars().enumerate() {
-                if i > 0 && i % 4 == 0 {
-                    result.push('_');
-                }
-                result.push(c);
-            }
-            //反转回来
-            result = result.chars().rev().collect();
-        }
-        result
-    }
-    
 
     pub fn get_data_error(&self) -> &DataError {
         &self.data_error
@@ -66,4 +65,31 @@ impl Data {
     pub fn set_data_error(&mut self, data_error: DataError) {
         self.data_error = data_error;
     }
+
+    //记录当前输入，重复的记录移到最前
+    pub fn push_history(&mut self) {
+        if self.input_data.is_empty() {
+            return;
+        }
+        let input_data = self.input_data.clone();
+        self.history.retain(|h| h != &input_data);
+        self.history.push_front(input_data);
+        self.history.truncate(HISTORY_LEN);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_error_is_localized() {
+        for error in [DataError::FormatError, DataError::LenNull, DataError::LenOver, DataError::Nice] {
+            let chinese = error.localized(Language::Chinese);
+            let english = error.localized(Language::English);
+            assert!(!chinese.is_empty() && !english.is_empty());
+            assert_ne!(chinese, english);
+            assert!(english.is_ascii(), "{}", english);
+        }
+    }
 }
@@ -0,0 +1,53 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::compression::*;
+use crate::convert::*;
+
+//超过该长度的解压结果只显示开头部分
+const DUMP_LIMIT: usize = 4096;
+
+pub fn decompress_hex(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut output = None;
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("解压(zlib/gzip/deflate)")).color(Color32::BLUE)).on_hover_text(t("输入压缩数据的16进制，可粘贴C数组或\\x转义形式，自动识别格式"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+
+        if data.input_data.trim().is_empty() {
+            data.set_data_error(DataError::LenNull);
+            return;
+        }
+        match parse_hex_bytes(&data.input_data) {
+            Some(bytes) => output = Some(decompress(&bytes)),
+            None => data.set_data_error(DataError::FormatError),
+        }
+    });
+    match (data.get_data_error(), output) {
+        (DataError::LenNull, _) => {
+            ui.colored_label(Color32::RED, t("请输入数值"));
+        }
+        (_, Some(Ok((format, bytes)))) => {
+            ui.horizontal(|ui| {
+                ui.add(Label::new(RichText::new(t("格式:")).color(Color32::BLUE)));
+                ui.monospace(format!("{:?}", format));
+                ui.separator();
+                ui.add(Label::new(RichText::new(t("解压后字节数:")).color(Color32::BLUE)));
+                ui.monospace(bytes.len().to_string());
+            });
+            copyable(ui, &hex_dump(&bytes[..bytes.len().min(DUMP_LIMIT)]));
+            if bytes.len() > DUMP_LIMIT {
+                ui.label(t("仅显示前{}字节").replace("{}", &DUMP_LIMIT.to_string()));
+            }
+        }
+        (_, Some(Err(_))) => {
+            ui.colored_label(Color32::RED, t("不是有效的zlib/gzip/deflate数据"));
+        }
+        (_, None) => {
+            ui.colored_label(Color32::RED, t("请输入偶数个16进制字符"));
+        }
+    }
+}
@@ -0,0 +1,389 @@
+//! 文本编码：Quoted-Printable、Punycode、Base58、Base64等
+
+use num::BigUint;
+
+//Quoted-Printable每行最多76个字符，软换行的"="占一个
+const QP_LINE_LEN: usize = 75;
+
+/// Quoted-Printable解码失败的原因
+#[derive(Debug, PartialEq)]
+pub enum QpError {
+    /// "="后不是两个16进制字符
+    InvalidEscape,
+    /// 解码后的字节不是合法的UTF-8
+    InvalidUtf8,
+}
+
+/// 按RFC 2045编码为Quoted-Printable
+///
+/// 不可打印字节和"="编码为"=XX"，行尾空白同样转义；换行输出为CRLF，
+/// 超过76列时插入软换行"=\r\n"
+///
+/// ```
+/// use number_conversion::encoding::quoted_printable_encode;
+/// assert_eq!(quoted_printable_encode("a=b é"), "a=3Db =C3=A9");
+/// assert_eq!(quoted_printable_encode("end "), "end=20");
+/// ```
+pub fn quoted_printable_encode(text: &str) -> String {
+    let mut result = String::new();
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            result.push_str("\r\n");
+        }
+        let bytes = line.strip_suffix('\r').unwrap_or(line).as_bytes();
+        let mut line_len = 0;
+        for (j, &byte) in bytes.iter().enumerate() {
+            let is_last = j + 1 == bytes.len();
+            let token = match byte {
+                b' ' | b'\t' if !is_last => (byte as char).to_string(),
+                33..=60 | 62..=126 => (byte as char).to_string(),
+                _ => format!("={:02X}", byte),
+            };
+            if line_len + token.len() > QP_LINE_LEN {
+                result.push_str("=\r\n");
+                line_len = 0;
+            }
+            line_len += token.len();
+            result.push_str(&token);
+        }
+    }
+    result
+}
+
+/// 解码Quoted-Printable，软换行"=\r\n"被移除，硬换行解码为"\n"
+///
+/// ```
+/// use number_conversion::encoding::{quoted_printable_decode, QpError};
+/// let encoded = "Now's the time =\r\nfor all folk to come=\r\n to the aid of their country.";
+/// assert_eq!(
+///     quoted_printable_decode(encoded).unwrap(),
+///     "Now's the time for all folk to come to the aid of their country."
+/// );
+/// assert_eq!(quoted_printable_decode("=C3=A9"), Ok("é".to_string()));
+/// assert_eq!(quoted_printable_decode("=G1"), Err(QpError::InvalidEscape));
+/// ```
+pub fn quoted_printable_decode(input: &str) -> Result<String, QpError> {
+    let mut bytes = Vec::new();
+    let lines: Vec<&str> = input.split('\n').collect();
+    for (i, line) in lines.iter().enumerate() {
+        //行尾空白是传输中添加的，解码时忽略
+        let line = line
+            .strip_suffix('\r')
+            .unwrap_or(line)
+            .trim_end_matches([' ', '\t']);
+        let (line, soft_break) = match line.strip_suffix('=') {
+            Some(line) => (line, true),
+            None => (line, false),
+        };
+        let mut rest = line.as_bytes();
+        while let Some((&byte, tail)) = rest.split_first() {
+            if byte == b'=' {
+                let escaped = match tail {
+                    [high, low, ..] => hex_pair(*high, *low).ok_or(QpError::InvalidEscape)?,
+                    _ => return Err(QpError::InvalidEscape),
+                };
+                bytes.push(escaped);
+                rest = &tail[2..];
+            } else {
+                bytes.push(byte);
+                rest = tail;
+            }
+        }
+        if !soft_break && i + 1 < lines.len() {
+            bytes.push(b'\n');
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| QpError::InvalidUtf8)
+}
+
+fn hex_pair(high: u8, low: u8) -> Option<u8> {
+    let high = (high as char).to_digit(16)?;
+    let low = (low as char).to_digit(16)?;
+    Some((high * 16 + low) as u8)
+}
+
+//RFC 3492 Punycode参数
+const PUNY_BASE: u32 = 36;
+const PUNY_TMIN: u32 = 1;
+const PUNY_TMAX: u32 = 26;
+const PUNY_SKEW: u32 = 38;
+const PUNY_DAMP: u32 = 700;
+const PUNY_INITIAL_BIAS: u32 = 72;
+const PUNY_INITIAL_N: u32 = 128;
+const ACE_PREFIX: &str = "xn--";
+
+/// Punycode转换失败的原因
+#[derive(Debug, PartialEq)]
+pub enum PunycodeError {
+    /// 解码的标签没有"xn--"前缀
+    MissingPrefix,
+    /// 含有不合法的字符
+    InvalidInput,
+    /// 计算过程溢出
+    Overflow,
+}
+
+/// 单个标签编码为"xn--"形式，纯ASCII标签原样返回
+///
+/// ```
+/// use number_conversion::encoding::punycode_encode;
+/// assert_eq!(punycode_encode("münchen").unwrap(), "xn--mnchen-3ya");
+/// assert_eq!(punycode_encode("example").unwrap(), "example");
+/// ```
+pub fn punycode_encode(label: &str) -> Result<String, PunycodeError> {
+    if label.is_ascii() {
+        return Ok(label.to_string());
+    }
+    let input: Vec<u32> = label.chars().map(|c| c as u32).collect();
+    let mut output: String = label.chars().filter(char::is_ascii).collect();
+    let basic_len = output.len() as u32;
+    if basic_len > 0 {
+        output.push('-');
+    }
+
+    let mut n = PUNY_INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = PUNY_INITIAL_BIAS;
+    let mut handled = basic_len;
+    while (handled as usize) < input.len() {
+        let m = input.iter().copied().filter(|&c| c >= n).min().ok_or(PunycodeError::InvalidInput)?;
+        delta = (m - n)
+            .checked_mul(handled + 1)
+            .and_then(|d| delta.checked_add(d))
+            .ok_or(PunycodeError::Overflow)?;
+        n = m;
+        for &c in &input {
+            if c < n {
+                delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+            }
+            if c == n {
+                let mut q = delta;
+                let mut k = PUNY_BASE;
+                loop {
+                    let t = puny_threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(puny_digit(t + (q - t) % (PUNY_BASE - t)));
+                    q = (q - t) / (PUNY_BASE - t);
+                    k += PUNY_BASE;
+                }
+                output.push(puny_digit(q));
+                bias = puny_adapt(delta, handled + 1, handled == basic_len);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+    Ok(format!("{}{}", ACE_PREFIX, output))
+}
+
+/// 解码单个"xn--"标签，前缀不区分大小写
+///
+/// ```
+/// use number_conversion::encoding::{punycode_decode, PunycodeError};
+/// assert_eq!(punycode_decode("xn--mnchen-3ya").unwrap(), "münchen");
+/// assert_eq!(punycode_decode("mnchen-3ya"), Err(PunycodeError::MissingPrefix));
+/// ```
+pub fn punycode_decode(label: &str) -> Result<String, PunycodeError> {
+    let encoded = label
+        .get(..ACE_PREFIX.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(ACE_PREFIX))
+        .map(|_| &label[ACE_PREFIX.len()..])
+        .ok_or(PunycodeError::MissingPrefix)?;
+    if !encoded.is_ascii() {
+        return Err(PunycodeError::InvalidInput);
+    }
+    //最后一个"-"之前是原样保留的ASCII字符
+    let (basic, extended) = match encoded.rfind('-') {
+        Some(pos) => (&encoded[..pos], &encoded[pos + 1..]),
+        None => ("", encoded),
+    };
+    let mut output: Vec<char> = basic.chars().collect();
+
+    let mut n = PUNY_INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias = PUNY_INITIAL_BIAS;
+    let mut digits = extended.chars();
+    while !digits.as_str().is_empty() {
+        let old_i = i;
+        let mut w: u32 = 1;
+        let mut k = PUNY_BASE;
+        loop {
+            let digit = digits
+                .next()
+                .and_then(puny_digit_value)
+                .ok_or(PunycodeError::InvalidInput)?;
+            i = digit
+                .checked_mul(w)
+                .and_then(|d| i.checked_add(d))
+                .ok_or(PunycodeError::Overflow)?;
+            let t = puny_threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(PUNY_BASE - t).ok_or(PunycodeError::Overflow)?;
+            k += PUNY_BASE;
+        }
+        let len = output.len() as u32 + 1;
+        bias = puny_adapt(i - old_i, len, old_i == 0);
+        n = n.checked_add(i / len).ok_or(PunycodeError::Overflow)?;
+        i %= len;
+        let c = char::from_u32(n).ok_or(PunycodeError::InvalidInput)?;
+        output.insert(i as usize, c);
+        i += 1;
+    }
+    Ok(output.into_iter().collect())
+}
+
+/// 域名逐个标签编码，如"münchen.de" -> "xn--mnchen-3ya.de"
+pub fn domain_to_ascii(domain: &str) -> Result<String, PunycodeError> {
+    domain
+        .split('.')
+        .map(punycode_encode)
+        .collect::<Result<Vec<String>, _>>()
+        .map(|labels| labels.join("."))
+}
+
+/// 域名中带"xn--"前缀的标签逐个解码，没有任何此类标签时返回MissingPrefix
+pub fn domain_to_unicode(domain: &str) -> Result<String, PunycodeError> {
+    let mut decoded_any = false;
+    let labels = domain
+        .split('.')
+        .map(|label| match punycode_decode(label) {
+            Ok(decoded) => {
+                decoded_any = true;
+                Ok(decoded)
+            }
+            Err(PunycodeError::MissingPrefix) => Ok(label.to_string()),
+            Err(e) => Err(e),
+        })
+        .collect::<Result<Vec<String>, _>>()?;
+    if !decoded_any {
+        return Err(PunycodeError::MissingPrefix);
+    }
+    Ok(labels.join("."))
+}
+
+fn puny_threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        PUNY_TMIN
+    } else if k >= bias + PUNY_TMAX {
+        PUNY_TMAX
+    } else {
+        k - bias
+    }
+}
+
+fn puny_adapt(mut delta: u32, num_points: u32, first_time: bool) -> u32 {
+    delta /= if first_time { PUNY_DAMP } else { 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((PUNY_BASE - PUNY_TMIN) * PUNY_TMAX) / 2 {
+        delta /= PUNY_BASE - PUNY_TMIN;
+        k += PUNY_BASE;
+    }
+    k + (PUNY_BASE - PUNY_TMIN + 1) * delta / (delta + PUNY_SKEW)
+}
+
+fn puny_digit(d: u32) -> char {
+    if d < 26 {
+        (b'a' + d as u8) as char
+    } else {
+        (b'0' + (d - 26) as u8) as char
+    }
+}
+
+fn puny_digit_value(c: char) -> Option<u32> {
+    match c {
+        'a'..='z' => Some(c as u32 - 'a' as u32),
+        'A'..='Z' => Some(c as u32 - 'A' as u32),
+        '0'..='9' => Some(c as u32 - '0' as u32 + 26),
+        _ => None,
+    }
+}
+
+//比特币使用的Base58字母表，去掉了容易混淆的0、O、I、l
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// Base58解码失败的原因
+#[derive(Debug, PartialEq)]
+pub enum Base58Error {
+    /// 不在Base58字母表中的字符
+    InvalidChar(char),
+}
+
+/// 按比特币字母表编码为Base58，开头的每个0字节编码为一个"1"
+///
+/// ```
+/// use number_conversion::encoding::base58_encode;
+/// assert_eq!(base58_encode(b"Hello World!"), "2NEpo7TZRRrLZSi2U");
+/// assert_eq!(base58_encode(&[0x00, 0x00, 0x28, 0x7f, 0xb4, 0xcd]), "11233QC4");
+/// ```
+pub fn base58_encode(bytes: &[u8]) -> String {
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    let mut result = "1".repeat(zeros);
+    if zeros < bytes.len() {
+        let digits = BigUint::from_bytes_be(&bytes[zeros..]).to_radix_be(58);
+        result.extend(digits.iter().map(|&d| BASE58_ALPHABET[d as usize] as char));
+    }
+    result
+}
+
+/// 解码Base58，开头的每个"1"还原为一个0字节
+///
+/// ```
+/// use number_conversion::encoding::{base58_decode, Base58Error};
+/// assert_eq!(base58_decode("11233QC4").unwrap(), vec![0x00, 0x00, 0x28, 0x7f, 0xb4, 0xcd]);
+/// assert_eq!(base58_decode("0OIl"), Err(Base58Error::InvalidChar('0')));
+/// ```
+pub fn base58_decode(input: &str) -> Result<Vec<u8>, Base58Error> {
+    let digits: Vec<u8> = input
+        .chars()
+        .map(|c| {
+            BASE58_ALPHABET
+                .iter()
+                .position(|&a| a as char == c)
+                .map(|d| d as u8)
+                .ok_or(Base58Error::InvalidChar(c))
+        })
+        .collect::<Result<_, _>>()?;
+    let zeros = digits.iter().take_while(|&&d| d == 0).count();
+    let mut result = vec![0u8; zeros];
+    if zeros < digits.len() {
+        //去掉前导"1"后首位不为0，from_radix_be不会失败
+        if let Some(number) = BigUint::from_radix_be(&digits[zeros..], 58) {
+            result.extend(number.to_bytes_be());
+        }
+    }
+    Ok(result)
+}
+
+/// RFC 4648的Base64字母表，按位值0~63排列
+pub const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// 按RFC 4648编码字节为Base64，不足3字节的末组用"="补齐
+///
+/// ```
+/// use number_conversion::encoding::base64_encode;
+/// assert_eq!(base64_encode(b"Man"), "TWFu");
+/// assert_eq!(base64_encode(b"Ma"), "TWE=");
+/// assert_eq!(base64_encode(&[0xff]), "/w==");
+/// assert_eq!(base64_encode(&[]), "");
+/// ```
+pub fn base64_encode(bytes: &[u8]) -> String {
+    let mut result = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                result.push(BASE64_ALPHABET[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
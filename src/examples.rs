@@ -0,0 +1,71 @@
+//! 各转换面板的示例输入，界面的"示例"菜单按面板名称查找
+
+/// (说明, 输入)，说明为中文时界面以它为键查找译文
+pub type Example = (&'static str, &'static str);
+
+/// 面板的示例输入，面板名称与界面上的输入框名称相同，没有示例时为空
+///
+/// 每个示例都能被对应面板成功转换：
+///
+/// ```
+/// use number_conversion::convert::*;
+/// use number_conversion::examples::examples;
+/// use number_conversion::units::*;
+/// for radix in [2, 8, 10, 16] {
+///     for (_, input) in examples(&format!("{}进制数", radix)) {
+///         let digits = input.strip_prefix('-').unwrap_or(input);
+///         assert_eq!(validate_radix(digits, radix, 64), Validation::Valid, "{}", input);
+///     }
+/// }
+/// for (_, input) in examples("智能输入") {
+///     let (radix, digits) = detect_number_format(input).unwrap();
+///     assert!(parse_radix(&digits, radix).is_ok(), "{}", input);
+/// }
+/// for (_, input) in examples("f32数据") {
+///     assert!(input.split(',').all(|v| validate_f32(v) == Validation::Valid), "{}", input);
+/// }
+/// for (_, input) in examples("f32的16进制数编码") {
+///     assert!(hex_to_f32(input).is_ok(), "{}", input);
+/// }
+/// for (_, input) in examples("多重解释") {
+///     assert!(multi_interpret_64(input).is_some(), "{}", input);
+/// }
+/// for (_, input) in examples("16进制转浮点数组") {
+///     assert!(split_hex_words(input, 8, false).iter().all(Result::is_ok), "{}", input);
+/// }
+/// for (_, input) in examples("字节检查器") {
+///     assert!(parse_hex_bytes(input).is_some_and(|bytes| !bytes.is_empty()), "{}", input);
+/// }
+/// for (_, input) in examples("单位换算") {
+///     let (value, from, to) = parse_unit_query(input).unwrap();
+///     assert!(convert_unit(value, from, to).is_ok(), "{}", input);
+/// }
+/// for (_, input) in examples("时长与节拍换算") {
+///     let (left, right) = input.split_once('@').unwrap();
+///     assert!(parse_frequency(right).is_some(), "{}", input);
+///     assert!(parse_duration(left).is_some() || detect_number_format(left).is_some(), "{}", input);
+/// }
+/// for (_, input) in examples("时间戳解析") {
+///     let (radix, digits) = detect_number_format(input).unwrap();
+///     assert!(parse_radix(&digits, radix).is_ok(), "{}", input);
+/// }
+/// assert!(examples("不存在").is_empty());
+/// ```
+pub fn examples(panel: &str) -> &'static [Example] {
+    match panel {
+        "智能输入" => &[("0xFF", "0xFF"), ("1010b", "1010b"), ("0o755", "0o755"), ("0xFFFF_FFFFu32", "0xFFFF_FFFFu32")],
+        "2进制数" => &[("255", "1111_1111"), ("0x8000", "1000_0000_0000_0000"), ("u32最大值", "1111_1111_1111_1111_1111_1111_1111_1111")],
+        "8进制数" => &[("rwxr-xr-x", "755"), ("rw-r--r--", "644"), ("255", "377")],
+        "10进制数" => &[("u8最大值", "255"), ("u32最大值", "4294967295"), ("-1", "-1")],
+        "16进制数" => &[("0xDEADBEEF", "DEADBEEF"), ("u16最大值", "FFFF"), ("u64最大值", "FFFF_FFFF_FFFF_FFFF")],
+        "f32数据" => &[("1.0", "1.0"), ("π", "3.1415927"), ("1.5e-3", "1.5e-3"), ("批量转换", "1.0, -2.5, 0.1")],
+        "f32的16进制数编码" => &[("1.0 (3F800000)", "3F800000"), ("π (40490FDB)", "40490FDB"), ("NaN (7FC00000)", "7FC00000"), ("-∞ (FF800000)", "FF800000")],
+        "多重解释" => &[("-1 (FFFFFFFF)", "FFFFFFFF"), ("1.0 (3F800000)", "3F800000"), ("f64 1.0 (3FF0000000000000)", "3FF0000000000000")],
+        "16进制转浮点数组" => &[("1.0, 2.0, -0.5", "3F800000 40000000 BF000000")],
+        "字节检查器" => &[("\"Hello\"", "48 65 6c 6c 6f"), ("C数组", "{0x3f, 0x80, 0x00, 0x00}")],
+        "单位换算" => &[("1.5 km -> m", "1.5 km -> m"), ("100C -> F", "100C -> F"), ("1 MiB -> bytes", "1 MiB -> bytes")],
+        "时长与节拍换算" => &[("1.5ms @ 72MHz", "1.5ms @ 72MHz"), ("72000 @ 72MHz", "72000 @ 72MHz"), ("0x100 @ 32.768kHz", "0x100 @ 32.768kHz")],
+        "时间戳解析" => &[("Unix秒", "1700000000"), ("Unix毫秒", "1700000000123"), ("FILETIME", "133444736000000000"), ("NTP", "0xE8FE_6F80_8000_0000")],
+        _ => &[],
+    }
+}
@@ -0,0 +1,63 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+
+//用符号、指数滑块和尾数编辑f32，编码保存在input_data中，以便与其他面板互相填入
+pub fn f32_fields(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut parts = F32Parts::new(hex_to_f32(&data.input_data).unwrap_or(0.0));
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("f32位域编辑")).color(Color32::BLUE)).on_hover_text(t("拖动指数、修改尾数，实时查看f32的值"));
+        ui.checkbox(&mut parts.sign, t("负号"));
+        ui.label(t("指数"));
+        ui.add(Slider::new(&mut parts.exponent, 0..=255));
+        ui.label(t("尾数"));
+        ui.add(DragValue::new(&mut parts.mantissa).clamp_range(0..=0x7F_FFFF).hexadecimal(6, false, settings.hex_uppercase));
+    });
+    let value = parts.compose();
+    data.input_data = f32_to_hex(value);
+    ui.horizontal(|ui| {
+        //结果保存原始形式，显示时按设置的精度和NaN/无穷大显示方式
+        data.set_output_data(settings.format_f32(value));
+        ui.add(Label::new(RichText::new(t("f32浮点数")).color(Color32::BLUE)));
+        copyable(ui, settings.special_value_label(value as f64).unwrap_or(&data.output_data));
+        ui.separator();
+        ui.add(Label::new(RichText::new(t("16进制编码")).color(Color32::BLUE)));
+        radix_copyable(ui, &settings.hex_case(f32_to_hex(value)), 16, settings);
+    });
+    ui.horizontal(|ui| {
+        //指数全0和全1的特殊情况
+        let category = match parts.category() {
+            FloatCategory::Zero => t("零(指数全0，尾数为0)"),
+            FloatCategory::Subnormal => t("非规格化数(指数全0，没有隐含的1)"),
+            FloatCategory::Normal => t("规格化数"),
+            FloatCategory::Infinity => t("无穷大(指数全1，尾数为0)"),
+            FloatCategory::Nan => t("NaN(指数全1，尾数非0)"),
+        };
+        ui.label(RichText::new(category).color(Color32::GRAY));
+        if let Some(exponent) = parts.unbiased_exponent() {
+            ui.label(RichText::new(t("无偏指数:{}").replace("{}", &exponent.to_string())).color(Color32::GRAY));
+        }
+        float_precision(false, ui);
+        let bits = format!("{:032b}", f32_bits(value));
+        ui.monospace(format!("{} {} {}", &bits[..1], &bits[1..9], &bits[9..]));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn value_follows_float_format() {
+        let settings = Settings { float_format: FloatFormat::Fixed, float_digits: 2, ..Settings::default() };
+        let mut data = Data::new();
+        data.input_data = "3fc00000".to_string();
+        run_panel(f32_fields, &mut data, &settings);
+        assert_eq!(data.output_data, "1.50");
+    }
+}
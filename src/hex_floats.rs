@@ -0,0 +1,64 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+
+//把一段16进制数据按f32或f64逐字转换，用于解析打包的浮点遥测数据
+pub fn hex_floats(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    //(是否f64, 是否小端序)，存放在egui的临时数据中
+    let mode_id = ui.id().with("16进制转浮点数组");
+    let (mut double, mut little_endian) = ui.data(|d| d.get_temp::<(bool, bool)>(mode_id)).unwrap_or((false, false));
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("16进制转浮点数组")).color(Color32::BLUE)).on_hover_text(t("按8位(f32)或16位(f64)切分成字逐个转换，空白、逗号和0x前缀被忽略"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+        example_menu(data, "16进制转浮点数组", ui);
+        ui.selectable_value(&mut double, false, "f32");
+        ui.selectable_value(&mut double, true, "f64");
+        ui.separator();
+        ui.selectable_value(&mut little_endian, false, t("大端序"));
+        ui.selectable_value(&mut little_endian, true, t("小端序"));
+    });
+    ui.data_mut(|d| d.insert_temp(mode_id, (double, little_endian)));
+    if data.input_data.trim().is_empty() {
+        data.set_data_error(DataError::LenNull);
+        ui.colored_label(Color32::RED, t("请输入数值"));
+        return;
+    }
+    let word_digits = if double { 16 } else { 8 };
+    let mut converted = Vec::new();
+    for (i, word) in split_hex_words(&data.input_data, word_digits, little_endian).into_iter().enumerate() {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(format!("#{}", i)).color(Color32::GRAY));
+            match word {
+                Ok(word) => {
+                    let text = if double {
+                        let (_, _, value) = multi_interpret_64(&word).unwrap_or_default();
+                        settings.special_value_label(value).map(str::to_string).unwrap_or_else(|| settings.format_f64(value))
+                    } else {
+                        let (_, _, value) = multi_interpret_32(&word).unwrap_or_default();
+                        settings.special_value_label(value as f64).map(str::to_string).unwrap_or_else(|| settings.format_f32(value))
+                    };
+                    ui.monospace(settings.hex_case(word));
+                    copyable(ui, &text);
+                    converted.push(text);
+                }
+                Err(HexWordError::InvalidChar(c)) => {
+                    data.set_data_error(DataError::FormatError);
+                    ui.colored_label(Color32::RED, t("包含非16进制字符'{}'").replace("{}", &c.to_string()));
+                }
+                Err(HexWordError::Incomplete(len)) => {
+                    data.set_data_error(DataError::FormatError);
+                    ui.colored_label(Color32::RED, t("末尾只有{}位，不足一个字").replace("{}", &len.to_string()));
+                }
+            }
+        });
+    }
+    //有一个字无法转换时不更新结果，上次的结果仍然完整
+    if data.get_data_error() == &DataError::Nice {
+        data.set_output_data(converted.join("\n"));
+    }
+}
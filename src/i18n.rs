@@ -0,0 +1,300 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+pub enum Language {
+    Chinese,
+    English,
+}
+
+impl Language {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Language::Chinese => "中文",
+            Language::English => "English",
+        }
+    }
+}
+
+//当前界面语言，每帧由设置同步
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_language(language: Language) {
+    LANGUAGE.store(language as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::English,
+        _ => Language::Chinese,
+    }
+}
+
+//以中文原文为键，没有译文时原样返回
+pub fn t(key: &'static str) -> &'static str {
+    match language() {
+        Language::Chinese => key,
+        Language::English => english().get(key).copied().unwrap_or(key),
+    }
+}
+
+fn english() -> &'static HashMap<&'static str, &'static str> {
+    static ENGLISH: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    ENGLISH.get_or_init(|| {
+        HashMap::from([
+            //设置与状态栏
+            ("设置", "Settings"),
+            ("语言", "Language"),
+            ("实时转换", "Live conversion"),
+            ("关闭后按回车或点击\"转换\"按钮才转换", "When off, convert only on Enter or the \"Convert\" button"),
+            ("转换", "Convert"),
+            ("屏幕键盘", "On-screen keypad"),
+            ("紧凑模式", "Compact mode"),
+            ("缩小行距并隐藏底部图片，转换功能不变", "Tighter line spacing and no picture at the bottom, conversions are unaffected"),
+            ("键盘", "Keypad"),
+            ("先点击一个输入框", "Click an input box first"),
+            ("清空", "Clear"),
+            ("恢复默认设置", "Reset to defaults"),
+            ("确定恢复所有设置的默认值？", "Reset every setting to its default value?"),
+            ("确定", "OK"),
+            ("取消", "Cancel"),
+            ("16进制大写输出", "Uppercase hex output"),
+            ("UTF-16使用小端序(LE)", "UTF-16 little endian (LE)"),
+            ("文本换行统一为\\n", "Normalize text line endings to \\n"),
+            ("编码前把Windows换行\\r\\n转换为\\n，关闭时\\r编码为000D", "Convert Windows line endings \\r\\n to \\n before encoding; when off, \\r is encoded as 000D"),
+            ("2进制结果分行显示", "Wrap binary results"),
+            ("保留上次结果", "Keep last result"),
+            ("输入无效时灰色显示上次的有效结果，修改输入时不会闪烁", "Show the last valid result grayed out while the input is invalid, so it does not flicker while editing"),
+            ("上次结果:", "Last result:"),
+            ("按2进制的分组位数分组，每行4组", "Grouped by the binary group size, 4 groups per line"),
+            ("输出分组", "Group output digits"),
+            ("以0开头的数在C语言中是8进制，这里按10进制转换", "A leading 0 means octal in C, this is converted as decimal"),
+            ("按8进制转换", "Convert as octal"),
+            ("填入8进制输入框", "Fill into the octal input"),
+            ("复制时带进制前缀", "Copy with radix prefix"),
+            ("复制2、8、16进制结果时加上0b、0o、0x前缀", "Add 0b, 0o or 0x when copying binary, octal or hex results"),
+            ("关闭后结果原样输出，复制到代码中时不带分隔符", "When off, results are shown and copied without separators, e.g. for pasting into code"),
+            ("分组位数", "Group size"),
+            ("2进制", "Binary"),
+            ("8进制", "Octal"),
+            ("10进制", "Decimal"),
+            ("16进制", "Hex"),
+            ("分隔符", "Separator"),
+            ("位宽", "Bit width"),
+            ("整数面板按位宽截断数值、补齐前导零，负数显示补码", "Integer converters mask values to the width, pad with leading zeros and show negatives in two's complement"),
+            ("自动", "Auto"),
+            ("最大输入位数", "Maximum input digits"),
+            ("前导零补齐", "Leading zero padding"),
+            ("不补齐", "None"),
+            ("整字节", "Whole bytes"),
+            ("固定位宽", "Fixed width"),
+            ("位", " bits"),
+            ("代码字面量", "Code literal"),
+            ("浮点数显示", "Float display"),
+            ("小数位数", "Decimal places"),
+            ("有效数字", "Significant digits"),
+            ("特殊值显示", "Special values"),
+            ("友好", "Friendly"),
+            ("原始", "Raw"),
+            ("NaN和无穷大显示为带说明的文字", "NaN and infinities are shown with a description"),
+            ("与Rust的输出一致，如NaN、inf、-inf", "Same as Rust's output, e.g. NaN, inf, -inf"),
+            ("NaN(非数)", "NaN (not a number)"),
+            ("+∞(正无穷大)", "+∞ (positive infinity)"),
+            ("-∞(负无穷大)", "-∞ (negative infinity)"),
+            ("单位换算小数位数", "Unit conversion decimal places"),
+            ("随机种子", "Random seed"),
+            ("应用种子", "Apply seed"),
+            ("种子须为u64范围内的整数", "The seed must be an integer in the u64 range"),
+            ("😄 源码仓库", "😄 Source repository"),
+            ("字符数", "chars"),
+            ("字节数", "bytes"),
+            ("未选中输入框", "No input focused"),
+            //通用部件
+            ("历史", "History"),
+            ("示例", "Examples"),
+            ("u8最大值", "u8 max"),
+            ("u16最大值", "u16 max"),
+            ("u32最大值", "u32 max"),
+            ("u64最大值", "u64 max"),
+            ("批量转换", "Batch"),
+            ("C数组", "C array"),
+            ("将结果填入反向转换的输入框", "Fill the result into the reverse converter"),
+            ("复制", "Copy"),
+            ("更新内容", "What's new"),
+            ("当前版本", "current version"),
+            ("首个版本", "First release"),
+            ("各面板新增\"示例\"菜单", "An \"Examples\" menu on each panel"),
+            ("新增字节检查器，一次查看字节转储、数值解释、f32编码分解和Base64", "New byte inspector showing the byte dump, interpretations, f32 breakdown and Base64 at once"),
+            ("新增时间戳解析，支持Unix秒、毫秒、FILETIME和NTP", "New timestamp panel for Unix seconds and milliseconds, FILETIME and NTP"),
+            ("16进制数可按补码显示有符号数，10进制数可显示大端序和小端序", "Hex input can show the two's complement signed value, decimal input can show big and little endian hex"),
+            ("f32编码支持把整数按位重解释", "The f32 encoder can reinterpret integers as raw bits"),
+            ("智能输入可逐行转换粘贴的多行数值", "The smart input converts pasted multi-line numbers line by line"),
+            ("设置中新增复制时带进制前缀、输出分组、保留上次结果和紧凑模式", "New settings: copy with radix prefix, output grouping, keep last result and compact mode"),
+            ("设置在重启后保留", "Settings are kept across restarts"),
+            ("剪贴历史", "Clipboard history"),
+            ("清空剪贴历史", "Clear clipboard history"),
+            ("有效位数:{}", "Significant bits:{}"),
+            ("字节数:{}", "Bytes:{}"),
+            ("输入宽度:{}位", "Input width:{} bits"),
+            ("代码字面量:", "Code literals:"),
+            ("位权分解", "Bit weights"),
+            ("作为字节数:{}", "As a byte count:{}"),
+            //面板标题
+            ("智能输入", "Smart input"),
+            ("进制对照表", "Base comparison"),
+            ("2进制数", "Binary"),
+            ("8进制数", "Octal"),
+            ("10进制数", "Decimal"),
+            ("16进制数", "Hexadecimal"),
+            ("f32数据", "f32 value"),
+            ("f32的16进制数编码", "f32 hex encoding"),
+            ("输入f32数据", "f32 value"),
+            ("输入f32的16进制数编码", "f32 hex encoding"),
+            ("f32位域编辑", "f32 field editor"),
+            ("多重解释", "Multiple interpretations"),
+            ("16进制转浮点数组", "Hex words to floats"),
+            ("字节检查器", "Byte inspector"),
+            ("字节与ASCII", "Bytes and ASCII"),
+            ("数值解释", "Interpretations"),
+            ("字符查询", "Character lookup"),
+            ("文本转UTF-16", "Text to UTF-16"),
+            ("UTF-16转文本", "UTF-16 to text"),
+            ("文本转Quoted-Printable", "Text to Quoted-Printable"),
+            ("Quoted-Printable转文本", "Quoted-Printable to text"),
+            ("域名转Punycode", "Domain to Punycode"),
+            ("Punycode转域名", "Punycode to domain"),
+            ("单位换算", "Unit conversion"),
+            ("时间戳解析", "Timestamps"),
+            ("时长与节拍换算", "Duration and ticks"),
+            ("16进制转Base58", "Hex to Base58"),
+            ("Base58转16进制", "Base58 to hex"),
+            ("解压", "Decompress"),
+            ("解压(zlib/gzip/deflate)", "Decompress (zlib/gzip/deflate)"),
+            //结果标签
+            ("2进制数:", "Binary:"),
+            ("10进制数:", "Decimal:"),
+            ("有符号10进制数:", "Signed decimal:"),
+            ("有符号", "Signed"),
+            ("按补码解释为有符号数，位宽为设置中的位宽，自动时为输入的位数", "Interpret as a two's complement signed value, using the bit width from the settings, or the input's width when automatic"),
+            ("16进制数:", "Hex:"),
+            ("2进制:", "Binary:"),
+            ("8进制:", "Octal:"),
+            ("10进制:", "Decimal:"),
+            ("16进制:", "Hex:"),
+            ("f32浮点数", "f32 value"),
+            ("16进制编码", "Hex encoding"),
+            ("数值编码", "Numeric"),
+            ("位重解释", "Raw bits"),
+            ("把输入作为数值编码，整数5按5.0编码为40A00000", "Encode the input as a number, the integer 5 is encoded as 5.0, i.e. 40A00000"),
+            ("把整数输入当作f32的32位位模式，5编码为00000005，负数按补码", "Treat an integer input as the 32 raw bits of an f32, 5 becomes 00000005, negative numbers use two's complement"),
+            ("位重解释需要32位以内的整数", "Raw bits need an integer that fits in 32 bits"),
+            ("码点:", "Code point:"),
+            ("节拍数:", "Ticks:"),
+            ("时长:", "Duration:"),
+            ("精确值{}，已四舍五入", "Exact value {}, rounded"),
+            ("输入10进制或带0x前缀的16进制整数，按各种时间戳格式显示为UTC时间", "Enter a decimal or 0x-prefixed hex integer to see it as UTC time in each timestamp format"),
+            ("Unix秒", "Unix seconds"),
+            ("Unix毫秒", "Unix milliseconds"),
+            ("Unix秒:", "Unix seconds:"),
+            ("Unix毫秒:", "Unix milliseconds:"),
+            ("超出0001~9999年", "Outside the years 0001 to 9999"),
+            ("文本:", "Text:"),
+            ("域名:", "Domain:"),
+            ("格式:", "Format:"),
+            ("解压后字节数:", "Decompressed bytes:"),
+            ("仅显示前{}字节", "Only the first {} bytes are shown"),
+            ("识别为{}进制", "Detected as base {}"),
+            ("超出{}位，已截断", "Exceeds {} bits, truncated"),
+            ("{}位，超过u64范围", "{} bits, beyond the u64 range"),
+            ("负号", "Negative"),
+            ("指数", "Exponent"),
+            ("尾数", "Mantissa"),
+            ("类别", "Category"),
+            ("零", "Zero"),
+            ("非规格化数", "Subnormal"),
+            ("规格化数", "Normal"),
+            ("无穷大", "Infinity"),
+            ("编码分解", "Encoding breakdown"),
+            ("输入未完成", "Input incomplete"),
+            ("零(指数全0，尾数为0)", "Zero (exponent all 0s, mantissa 0)"),
+            ("非规格化数(指数全0，没有隐含的1)", "Subnormal (exponent all 0s, no implicit 1)"),
+            ("规格化数", "Normal"),
+            ("无偏指数:{}", "Unbiased exponent:{}"),
+            ("10进制精度:{}~{}位有效数字", "Decimal precision:{} to {} significant digits"),
+            ("无穷大(指数全1，尾数为0)", "Infinity (exponent all 1s, mantissa 0)"),
+            ("NaN(指数全1，尾数非0)", "NaN (exponent all 1s, mantissa non-zero)"),
+            ("半字节反转", "Reverse nibbles"),
+            ("字节反转", "Reverse bytes"),
+            ("原始:", "Original:"),
+            ("随机", "Random"),
+            ("大端序", "Big endian"),
+            ("小端序", "Little endian"),
+            ("字节序", "Byte order"),
+            ("同时显示大端序和小端序的16进制，宽度为设置中的位宽，自动时取整字节", "Also show the hex in big and little endian byte order, using the bit width from the settings or whole bytes when automatic"),
+            //悬停提示
+            ("可输入下划线做视觉分割，负数按位宽显示补码", "Underscores may be used as separators, negatives are shown in two's complement at the bit width"),
+            ("可输入下划线做视觉分割，可带0x前缀", "Underscores may be used as separators, optional 0x prefix"),
+            ("可输入下划线做视觉分割，可带0b前缀", "Underscores may be used as separators, optional 0b prefix"),
+            ("可输入下划线做视觉分割，可带0o前缀", "Underscores may be used as separators, optional 0o prefix"),
+            ("可粘贴C数组或\\x转义形式，开头的00字节编码为1", "C arrays and \\x escapes may be pasted, leading 00 bytes encode as 1"),
+            ("可输入下划线做视觉分割，支持科学计数法(如1.5e-3、2E10)，多个数据用逗号或换行分隔", "Underscores may be used as separators, scientific notation (e.g. 1.5e-3, 2E10) is supported, separate multiple values with commas or newlines"),
+            ("可输入空格或下划线做视觉分割，BOM不做特殊处理", "Spaces or underscores may be used as separators, a BOM is not treated specially"),
+            ("比特币字母表，不含0、O、I、l", "Bitcoin alphabet, without 0, O, I and l"),
+            ("输入单个字符，或英文名称片段(如ACUTE)", "A single character, or part of its English name (e.g. ACUTE)"),
+            ("输入压缩数据的16进制，可粘贴C数组或\\x转义形式，自动识别格式", "Hex of compressed data, C arrays and \\x escapes may be pasted, the format is detected automatically"),
+            ("字节序在设置中选择，不添加BOM", "Byte order is chosen in the settings, no BOM is added"),
+            ("每行超过76个字符时插入软换行", "Soft line breaks are inserted after 76 characters"),
+            ("支持\"=\\r\\n\"软换行", "\"=\\r\\n\" soft line breaks are supported"),
+            ("如münchen.de，非ASCII的标签转换为xn--形式", "e.g. münchen.de, non-ASCII labels become xn-- labels"),
+            ("如xn--mnchen-3ya.de，至少一个标签以xn--开头", "e.g. xn--mnchen-3ya.de, at least one label must start with xn--"),
+            ("格式如\"1 km -> m\"，支持单位:{}", "Format like \"1 km -> m\", supported units:{}"),
+            ("格式如\"1.5ms @ 72MHz\"，左边为整数节拍数时换算为时长；时长单位ns/us/ms/s，频率单位mHz/Hz/kHz/MHz/GHz，词头区分大小写", "Format like \"1.5ms @ 72MHz\", an integer tick count on the left converts to a duration; duration units ns/us/ms/s, frequency units mHz/Hz/kHz/MHz/GHz, prefixes are case-sensitive"),
+            ("拖动指数、修改尾数，实时查看f32的值", "Drag the exponent or edit the mantissa to see the f32 value live"),
+            ("8位以内的16进制看作u32、i32和f32，9~16位看作u64、i64和f64", "Up to 8 hex digits are read as u32, i32 and f32, 9 to 16 digits as u64, i64 and f64"),
+            ("自动识别进制，一行列出各进制的表示，数值不限于64位", "The base is detected automatically and every base is listed in one row, values are not limited to 64 bits"),
+            ("按8位(f32)或16位(f64)切分成字逐个转换，空白、逗号和0x前缀被忽略", "Split into 8-digit (f32) or 16-digit (f64) words and convert each, whitespace, commas and 0x prefixes are ignored"),
+            ("按字节反转后再解释，用于小端序的数据", "Reverse the bytes before interpreting, for little-endian data"),
+            ("输入16进制数据，同时查看字节转储、数值解释、f32编码分解和Base64，可粘贴C数组或\\x转义形式", "Enter hex data to see the byte dump, interpretations, f32 breakdown and Base64 at once; C arrays and \\x escapes can be pasted"),
+            ("支持0x/0b/0o前缀、h/b/o后缀、u8等类型后缀和10进制数，自动识别进制", "Accepts 0x/0b/0o prefixes, h/b/o suffixes, type suffixes like u8 and decimal numbers, the base is detected automatically"),
+            //错误信息
+            ("请输入数值", "Please enter a value"),
+            ("请输入文本", "Please enter text"),
+            ("请输入2进制字符", "Please enter binary digits"),
+            ("请输入8进制字符", "Please enter octal digits"),
+            ("请输入10进制字符", "Please enter decimal digits"),
+            ("请输入16进制字符", "Please enter hex digits"),
+            ("请输入偶数个16进制字符", "Please enter an even number of hex digits"),
+            ("请输入f32数据", "Please enter an f32 value"),
+            ("请输入Base58字符", "Please enter Base58 characters"),
+            ("请输入字符或名称", "Please enter a character or name"),
+            ("请输入域名", "Please enter a domain"),
+            ("(无效字符:{})", " (invalid characters: {})"),
+            ("(输入包含多行，可在智能输入中逐行转换)", " (the input has several lines, the smart input can convert them line by line)"),
+            ("数值大于u64最大值:{}", "Value exceeds the u64 maximum:{}"),
+            ("数值超出i64范围", "Value is outside the i64 range"),
+            ("数值长度超过{}位", "Value is longer than {} digits"),
+            ("包含非16进制字符'{}'", "Contains the non-hex character '{}'"),
+            ("末尾只有{}位，不足一个字", "Only {} digits left at the end, not a whole word"),
+            ("仅解释前8字节", "Only the first 8 bytes are interpreted"),
+            ("字节反转需要偶数个字符", "Reversing bytes needs an even number of digits"),
+            ("字符'{}'不在Base58字母表中", "Character '{}' is not in the Base58 alphabet"),
+            ("未找到匹配的字符", "No matching character found"),
+            ("不是有效的zlib/gzip/deflate数据", "Not valid zlib/gzip/deflate data"),
+            ("数值溢出，域名过长", "Numeric overflow, the domain is too long"),
+            ("没有以xn--开头的标签", "No label starts with xn--"),
+            ("不是有效的Punycode", "Not valid Punycode"),
+            ("\"=\"后需要两个16进制字符", "\"=\" must be followed by two hex digits"),
+            ("解码结果不是有效的UTF-8", "Decoded bytes are not valid UTF-8"),
+            ("长度需为4个16进制字符的倍数", "Length must be a multiple of 4 hex digits"),
+            ("存在未配对的代理项", "Unpaired surrogate"),
+            ("未知单位:{}", "Unknown unit:{}"),
+            ("单位类别不同，无法换算", "Units belong to different categories"),
+            ("请按\"数值 单位 -> 单位\"的格式输入", "Please enter \"value unit -> unit\""),
+            ("节拍数超出u64范围", "Tick count exceeds the u64 range"),
+            ("请按\"时长 @ 频率\"或\"节拍数 @ 频率\"的格式输入", "Please enter \"duration @ frequency\" or \"ticks @ frequency\""),
+            ("无法识别进制，请使用对应的进制输入框", "Cannot detect the base, please use the matching converter"),
+        ])
+    })
+}
@@ -0,0 +1,84 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+use crate::encoding::*;
+
+//一次输入16进制数据，同时显示字节转储、数值解释、f32编码分解和Base64，各部分复用已有的转换函数和控件
+pub fn inspector(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut bytes = Vec::new();
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("字节检查器")).color(Color32::BLUE)).on_hover_text(t("输入16进制数据，同时查看字节转储、数值解释、f32编码分解和Base64，可粘贴C数组或\\x转义形式"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+        example_menu(data, "字节检查器", ui);
+
+        if data.input_data.trim().is_empty() {
+            data.set_data_error(DataError::LenNull);
+            return;
+        }
+        match parse_hex_bytes(&data.input_data) {
+            Some(parsed) if parsed.is_empty() => data.set_data_error(DataError::LenNull),
+            Some(parsed) => bytes = parsed,
+            None => data.set_data_error(DataError::FormatError),
+        }
+    });
+    match data.get_data_error() {
+        DataError::LenNull => {
+            ui.colored_label(Color32::RED, t("请输入数值"));
+            return;
+        }
+        DataError::FormatError => {
+            ui.colored_label(Color32::RED, t("请输入偶数个16进制字符"));
+            return;
+        }
+        _ => {}
+    }
+    data.set_output_data(bytes.iter().map(|b| format!("{:02x}", b)).collect());
+    //固定id并放在独立的id范围内，切换语言时保持展开状态，也不与其他面板的折叠栏冲突
+    ui.push_id("字节检查器", |ui| {
+        CollapsingHeader::new(t("字节与ASCII")).id_source("字节与ASCII").default_open(true).show(ui, |ui| {
+            copyable(ui, &hex_dump(&bytes));
+        });
+        //超过8字节时只解释开头的8字节
+        let head: String = bytes.iter().take(8).map(|b| format!("{:02x}", b)).collect();
+        CollapsingHeader::new(t("数值解释")).id_source("数值解释").default_open(true).show(ui, |ui| {
+            if bytes.len() > 8 {
+                ui.label(RichText::new(t("仅解释前8字节")).color(Color32::GRAY));
+            }
+            let (unsigned, interpretations) = if head.len() > 8 {
+                let (unsigned, signed, float) = multi_interpret_64(&head).unwrap_or_default();
+                let float = settings.special_value_label(float).map(str::to_string).unwrap_or_else(|| settings.format_f64(float));
+                (unsigned, [("u64:", unsigned.to_string()), ("i64:", signed.to_string()), ("f64:", float)])
+            } else {
+                let (unsigned, signed, float) = multi_interpret_32(&head).unwrap_or_default();
+                let float = settings.special_value_label(float as f64).map(str::to_string).unwrap_or_else(|| settings.format_f32(float));
+                (unsigned as u64, [("u32:", unsigned.to_string()), ("i32:", signed.to_string()), ("f32:", float)])
+            };
+            ui.horizontal(|ui| {
+                for (i, (label, text)) in interpretations.into_iter().enumerate() {
+                    if i > 0 {
+                        ui.separator();
+                    }
+                    ui.add(Label::new(RichText::new(label).color(Color32::BLUE)));
+                    copyable(ui, &text);
+                }
+            });
+            ui.horizontal(|ui| {
+                ui.add(Label::new(RichText::new(t("2进制数:")).color(Color32::BLUE)));
+                binary_output(ui, &format!("{:0width$b}", unsigned, width = head.len() * 4), settings);
+            });
+            bit_stats(unsigned, Some(head.len() * 4), ui);
+        });
+        if bytes.len() >= 4 {
+            let float = f32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            f32_breakdown(float, settings, ui);
+        }
+        CollapsingHeader::new("Base64").id_source("Base64").default_open(true).show(ui, |ui| {
+            copyable(ui, &base64_encode(&bytes));
+        });
+    });
+}
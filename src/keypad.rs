@@ -0,0 +1,36 @@
+use crate::i18n::*;
+use crate::data::*;
+use eframe::egui;
+use egui::*;
+
+//屏幕键盘，按键追加到目标输入框，超出目标进制的数字不可用
+pub fn keypad(target: Option<(&'static str, Option<u32>, &mut Data)>, ui: &mut Ui) {
+    ui.label(RichText::from(t("键盘")).color(Color32::BLUE));
+    let Some((name, radix, data)) = target else {
+        ui.label(t("先点击一个输入框"));
+        return;
+    };
+    ui.label(t(name));
+    let key_size = vec2(36.0, 36.0);
+    Grid::new("键盘按键").show(ui, |ui| {
+        for (i, digit) in "0123456789ABCDEF".chars().enumerate() {
+            let enabled = radix.is_none_or(|radix| digit.is_digit(radix));
+            if ui.add_enabled(enabled, Button::new(digit.to_string()).min_size(key_size)).clicked() {
+                data.input_data.push(digit);
+            }
+            if i % 4 == 3 {
+                ui.end_row();
+            }
+        }
+        if ui.add(Button::new("_").min_size(key_size)).clicked() {
+            data.input_data.push('_');
+        }
+        if ui.add(Button::new("⌫").min_size(key_size)).clicked() {
+            data.input_data.pop();
+        }
+        if ui.add(Button::new(t("清空")).min_size(key_size)).clicked() {
+            data.input_data.clear();
+        }
+        ui.end_row();
+    });
+}
@@ -0,0 +1,65 @@
+//! 进制转换的核心函数，不依赖egui，可作为库单独使用；开启gui特性时另有可嵌入egui程序的转换框和完整的转换程序
+
+pub mod convert;
+pub mod unicode;
+pub mod encoding;
+pub mod converter;
+pub mod units;
+pub mod examples;
+pub mod timestamp;
+#[cfg(feature = "compression")]
+pub mod compression;
+#[cfg(feature = "gui")]
+pub mod widget;
+#[cfg(feature = "gui")]
+pub mod app;
+
+//界面的各个面板和控件，只在app中使用
+#[cfg(feature = "gui")]
+mod base2;
+#[cfg(feature = "gui")]
+mod base8;
+#[cfg(feature = "gui")]
+mod base10;
+#[cfg(feature = "gui")]
+mod base16;
+#[cfg(feature = "gui")]
+mod base32_f32;
+#[cfg(feature = "gui")]
+mod basef32_32;
+#[cfg(feature = "gui")]
+mod changelog;
+#[cfg(feature = "gui")]
+mod char_lookup;
+#[cfg(feature = "gui")]
+mod compare_bases;
+#[cfg(feature = "gui")]
+mod converter_panel;
+#[cfg(feature = "gui")]
+mod data;
+#[cfg(all(feature = "gui", feature = "compression"))]
+mod decompress;
+#[cfg(feature = "gui")]
+mod f32_fields;
+#[cfg(feature = "gui")]
+mod hex_floats;
+#[cfg(feature = "gui")]
+mod i18n;
+#[cfg(feature = "gui")]
+mod inspector;
+#[cfg(feature = "gui")]
+mod keypad;
+#[cfg(feature = "gui")]
+mod multi_interpret;
+#[cfg(feature = "gui")]
+mod settings;
+#[cfg(feature = "gui")]
+mod ticks;
+#[cfg(feature = "gui")]
+mod timestamps;
+#[cfg(feature = "gui")]
+mod unit_convert;
+#[cfg(feature = "gui")]
+mod universal;
+#[cfg(feature = "gui")]
+mod widgets;
@@ -0,0 +1,91 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+
+pub fn multi_interpret(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    //(位宽, 各解释)，位宽为32或64
+    let mut output: Option<(u32, [(&str, String); 3])> = None;
+    let mut invalid = Vec::new();
+    //是否按字节反转后解释，存放在egui的临时数据中
+    let swap_id = ui.id().with("多重解释字节反转");
+    let mut swap = ui.data(|d| d.get_temp::<bool>(swap_id)).unwrap_or(false);
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("多重解释")).color(Color32::BLUE)).on_hover_text(t("8位以内的16进制看作u32、i32和f32，9~16位看作u64、i64和f64"));
+        data.has_focus = radix_input_edit(data, settings, 16, ui).has_focus();
+        example_menu(data, "多重解释", ui);
+        ui.checkbox(&mut swap, t("字节反转")).on_hover_text(t("按字节反转后再解释，用于小端序的数据"));
+
+        //允许输入"_"做视觉区分，忽略"0x"前缀和u32等类型后缀
+        let raw_data = strip_radix_prefix(strip_type_suffix(&data.ref_input_data().replace("_", "")), 16).to_string();
+        length_indicator(raw_data.len(), 16, ui);
+        if raw_data.is_empty() {
+            data.set_data_error(DataError::LenNull);
+        } else if !raw_data.chars().all(|c| c.is_ascii_hexdigit()) {
+            data.set_data_error(DataError::FormatError);
+            invalid = invalid_chars(&raw_data, 16);
+        } else if raw_data.len() > 16 {
+            data.set_data_error(DataError::LenOver);
+        } else if raw_data.len() > 8 {
+            let raw_data = if swap { swap_float_bytes(&raw_data, 64).unwrap_or(raw_data) } else { raw_data };
+            output = multi_interpret_64(&raw_data)
+                .map(|(unsigned, signed, float)| (64, [("u64:", unsigned.to_string()), ("i64:", signed.to_string()), ("f64:", float_text(settings.special_value_label(float), settings.format_f64(float)))]));
+        } else {
+            let raw_data = if swap { swap_float_bytes(&raw_data, 32).unwrap_or(raw_data) } else { raw_data };
+            output = multi_interpret_32(&raw_data)
+                .map(|(unsigned, signed, float)| (32, [("u32:", unsigned.to_string()), ("i32:", signed.to_string()), ("f32:", float_text(settings.special_value_label(float as f64), settings.format_f32(float)))]));
+        }
+    });
+    ui.data_mut(|d| d.insert_temp(swap_id, swap));
+    ui.horizontal(|ui| {
+        match (data.get_data_error(), output) {
+            (DataError::LenNull, _) => {
+                ui.colored_label(Color32::RED, t("请输入数值"));
+            }
+            (DataError::LenOver, _) => {
+                ui.colored_label(Color32::RED, t("数值长度超过{}位").replace("{}", "16"));
+            }
+            (DataError::FormatError, _) => {
+                format_error(ui, t("请输入16进制字符"), &invalid);
+            }
+            (_, Some((bits, interpretations))) => {
+                data.set_output_data(interpretations.iter().map(|(label, text)| format!("{}{}", label, text)).collect::<Vec<String>>().join(" "));
+                for (i, (label, string_data)) in interpretations.into_iter().enumerate() {
+                    if i > 0 {
+                        ui.separator();
+                    }
+                    ui.add(Label::new(RichText::new(label).color(Color32::BLUE)));
+                    copyable(ui, &string_data);
+                }
+                ui.separator();
+                float_precision(bits == 64, ui);
+            }
+            (_, None) => {
+                ui.colored_label(Color32::RED, t("请输入16进制字符"));
+            }
+        }
+    });
+}
+
+//特殊值优先显示友好说明
+fn float_text(label: Option<&str>, formatted: String) -> String {
+    label.map(str::to_string).unwrap_or(formatted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_suffix_is_ignored() {
+        let mut data = Data::new();
+        data.input_data = "FFFFFFFFu32".to_string();
+        run_panel(multi_interpret, &mut data, &Settings::new());
+        assert!(data.get_data_error() == &DataError::Nice);
+        assert!(data.output_data.starts_with("u32:4294967295 i32:-1"), "{}", data.output_data);
+    }
+}
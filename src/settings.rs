@@ -0,0 +1,368 @@
+use crate::i18n::*;
+use crate::widgets::*;
+use eframe::egui;
+use crate::converter::ConvertOptions;
+use crate::convert::{format_float, mask_to_width, pad_digits, separate_digits, with_radix_prefix, FloatFormat, LiteralLanguage};
+use std::time::{SystemTime, UNIX_EPOCH};
+use egui::*;
+use serde::{Deserialize, Serialize};
+
+//2进制和16进制输出的前导零补齐方式
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Padding {
+    None,
+    Byte,
+    Fixed,
+}
+
+//通过eframe的存储保存，新版本增加的字段在旧配置中缺失时取默认值
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub hex_uppercase: bool,
+    pub utf16_little_endian: bool,
+    //文本编码前把粘贴进来的"\r\n"换行统一为"\n"，关闭时原样编码
+    pub normalize_newlines: bool,
+    pub language: Language,
+    pub binary_group_size: usize,
+    pub octal_group_size: usize,
+    pub decimal_group_size: usize,
+    pub hex_group_size: usize,
+    pub group_separator: char,
+    //关闭后输出和复制的内容都不插入分隔符
+    pub group_output: bool,
+    //复制2、8、16进制结果时加上0b、0o、0x前缀
+    pub prefix_on_copy: bool,
+    pub unit_precision: usize,
+    //不保存，每次启动重新取时间种子
+    #[serde(skip, default = "time_seed")]
+    pub random_seed: u64,
+    pub live_conversion: bool,
+    pub show_keypad: bool,
+    //紧凑模式缩小行距并隐藏底部图片，适合小屏幕
+    pub compact: bool,
+    pub literal_language: LiteralLanguage,
+    pub padding: Padding,
+    pub pad_bits: u32,
+    pub wrap_binary: bool,
+    //输入无效时在错误提示下方灰色显示上次的有效结果
+    pub keep_last_output: bool,
+    //整数面板的位宽，None为自动(不截断，负数按64位)
+    pub bit_width: Option<u32>,
+    pub float_format: FloatFormat,
+    pub float_digits: usize,
+    pub friendly_special_values: bool,
+    //2进制和16进制输入的最大位数，不超过u64能容纳的位数
+    pub max_binary_len: usize,
+    pub max_hex_len: usize,
+    //上次看过更新说明的版本，升级后据此自动打开更新说明
+    pub last_seen_version: Option<String>,
+}
+
+impl Default for Settings {
+    //各项的默认值，随机种子固定为0，启动时由Settings::new换成时间种子
+    fn default() -> Settings {
+        Settings {
+            hex_uppercase: false,
+            utf16_little_endian: false,
+            normalize_newlines: false,
+            language: Language::Chinese,
+            binary_group_size: 4,
+            octal_group_size: 3,
+            decimal_group_size: 3,
+            hex_group_size: 4,
+            group_separator: '_',
+            group_output: true,
+            prefix_on_copy: false,
+            unit_precision: 6,
+            random_seed: 0,
+            live_conversion: true,
+            show_keypad: false,
+            compact: false,
+            literal_language: LiteralLanguage::Rust,
+            padding: Padding::None,
+            pad_bits: 32,
+            wrap_binary: false,
+            keep_last_output: false,
+            bit_width: None,
+            float_format: FloatFormat::Auto,
+            float_digits: 6,
+            friendly_special_values: true,
+            max_binary_len: 64,
+            max_hex_len: 16,
+            last_seen_version: None,
+        }
+    }
+}
+
+fn time_seed() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or(0)
+}
+
+impl Settings {
+    //默认以启动时间(毫秒)为种子，需要复现时在设置中指定
+    pub fn new() -> Settings {
+        Settings { random_seed: time_seed(), ..Settings::default() }
+    }
+    //各进制输出的(分组位数, 分隔符)，分组位数为0或关闭输出分组时不分组
+    pub fn grouping(&self, radix: u32) -> (usize, char) {
+        if !self.group_output {
+            return (0, self.group_separator);
+        }
+        let size = match radix {
+            2 => self.binary_group_size,
+            8 => self.octal_group_size,
+            10 => self.decimal_group_size,
+            _ => self.hex_group_size,
+        };
+        (size, self.group_separator)
+    }
+    //按该进制的分组设置插入分隔符
+    pub fn group(&self, digits: &str, radix: u32) -> String {
+        let (size, separator) = self.grouping(radix);
+        separate_digits(digits, size, separator)
+    }
+    //复制按钮实际复制的内容，加前缀时去掉分组分隔符，避免得到"0xff ff"这样的无效字面量
+    pub fn copy_prefix(&self, text: &str, radix: u32) -> String {
+        if self.prefix_on_copy && matches!(radix, 2 | 8 | 16) {
+            with_radix_prefix(&text.replace(self.group_separator, ""), radix)
+        } else {
+            text.to_string()
+        }
+    }
+    //除随机种子外的所有设置恢复为默认值，种子保持不变，随机数序列不受影响；看过的更新说明版本也保留
+    pub fn reset_to_defaults(&mut self) {
+        let last_seen_version = self.last_seen_version.take();
+        *self = Settings { random_seed: self.random_seed, last_seen_version, ..Settings::default() };
+    }
+    //通用转换器用到的设置
+    pub fn convert_options(&self) -> ConvertOptions {
+        ConvertOptions {
+            hex_uppercase: self.hex_uppercase,
+            utf16_little_endian: self.utf16_little_endian,
+            normalize_newlines: self.normalize_newlines,
+        }
+    }
+    //按设置调整16进制输出的大小写
+    pub fn hex_case(&self, hex: String) -> String {
+        if self.hex_uppercase {
+            hex.to_uppercase()
+        } else {
+            hex
+        }
+    }
+    //按位宽截断数值，返回截断后的值和是否溢出
+    pub fn mask(&self, value: u64) -> (u64, bool) {
+        mask_to_width(value, self.width_bits())
+    }
+    //负数取补码使用的位宽
+    pub fn width_bits(&self) -> u32 {
+        self.bit_width.unwrap_or(64)
+    }
+    //按设置格式化f32结果，自动时使用f32自身的最短表示
+    pub fn format_f32(&self, value: f32) -> String {
+        match self.float_format {
+            FloatFormat::Auto => value.to_string(),
+            format => format_float(value as f64, format, self.float_digits),
+        }
+    }
+    pub fn format_f64(&self, value: f64) -> String {
+        format_float(value, self.float_format, self.float_digits)
+    }
+    //友好显示时NaN和无穷大的说明文字，原始显示或有限值时为None
+    pub fn special_value_label(&self, value: f64) -> Option<&'static str> {
+        if !self.friendly_special_values {
+            return None;
+        }
+        if value.is_nan() {
+            Some(t("NaN(非数)"))
+        } else if value == f64::INFINITY {
+            Some(t("+∞(正无穷大)"))
+        } else if value == f64::NEG_INFINITY {
+            Some(t("-∞(负无穷大)"))
+        } else {
+            None
+        }
+    }
+    //按设置给2进制和16进制输出补前导零，Byte补齐到整字节，选定位宽时补齐到位宽
+    pub fn pad(&self, digits: String, radix: u32) -> String {
+        if let Some(bits) = self.bit_width {
+            return pad_digits(&digits, radix, bits);
+        }
+        let bits = match self.padding {
+            Padding::None => return digits,
+            Padding::Byte => (digits.len() as u32 * if radix == 2 { 1 } else { 4 }).div_ceil(8) * 8,
+            Padding::Fixed => self.pad_bits,
+        };
+        pad_digits(&digits, radix, bits)
+    }
+}
+
+//确认恢复默认设置时返回true，调用方据此立即保存
+pub fn settings(settings: &mut Settings, ui: &mut Ui) -> bool {
+    let mut reset = false;
+    //固定id，切换语言时保持展开状态
+    CollapsingHeader::new(t("设置")).id_source("设置").show(ui, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(t("语言"));
+            for language in [Language::Chinese, Language::English] {
+                ui.selectable_value(&mut settings.language, language, language.name());
+            }
+        });
+        ui.checkbox(&mut settings.live_conversion, t("实时转换")).on_hover_text(t("关闭后按回车或点击\"转换\"按钮才转换"));
+        ui.checkbox(&mut settings.show_keypad, t("屏幕键盘"));
+        ui.checkbox(&mut settings.compact, t("紧凑模式")).on_hover_text(t("缩小行距并隐藏底部图片，转换功能不变"));
+        ui.checkbox(&mut settings.hex_uppercase, t("16进制大写输出"));
+        ui.checkbox(&mut settings.utf16_little_endian, t("UTF-16使用小端序(LE)"));
+        ui.checkbox(&mut settings.normalize_newlines, t("文本换行统一为\\n")).on_hover_text(t("编码前把Windows换行\\r\\n转换为\\n，关闭时\\r编码为000D"));
+        ui.checkbox(&mut settings.wrap_binary, t("2进制结果分行显示")).on_hover_text(t("按2进制的分组位数分组，每行4组"));
+        ui.checkbox(&mut settings.keep_last_output, t("保留上次结果")).on_hover_text(t("输入无效时灰色显示上次的有效结果，修改输入时不会闪烁"));
+        ui.checkbox(&mut settings.prefix_on_copy, t("复制时带进制前缀")).on_hover_text(t("复制2、8、16进制结果时加上0b、0o、0x前缀"));
+        ui.checkbox(&mut settings.group_output, t("输出分组")).on_hover_text(t("关闭后结果原样输出，复制到代码中时不带分隔符"));
+        ui.add_enabled_ui(settings.group_output, |ui| ui.horizontal(|ui| {
+            ui.label(t("分组位数"));
+            for (label, size) in [
+                (t("2进制"), &mut settings.binary_group_size),
+                (t("8进制"), &mut settings.octal_group_size),
+                (t("10进制"), &mut settings.decimal_group_size),
+                (t("16进制"), &mut settings.hex_group_size),
+            ] {
+                ui.label(label);
+                ui.add(DragValue::new(size).clamp_range(0..=16));
+            }
+            ui.label(t("分隔符"));
+            for (separator, name) in [('_', "_"), (' ', "␣"), (',', ","), ('\'', "'")] {
+                ui.selectable_value(&mut settings.group_separator, separator, name);
+            }
+        }));
+        ui.horizontal(|ui| {
+            ui.label(t("位宽")).on_hover_text(t("整数面板按位宽截断数值、补齐前导零，负数显示补码"));
+            ui.selectable_value(&mut settings.bit_width, None, t("自动"));
+            for bits in [8, 16, 32, 64] {
+                ui.selectable_value(&mut settings.bit_width, Some(bits), bits.to_string());
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("最大输入位数"));
+            ui.label(t("2进制"));
+            ui.add(DragValue::new(&mut settings.max_binary_len).clamp_range(1..=64));
+            ui.label(t("16进制"));
+            ui.add(DragValue::new(&mut settings.max_hex_len).clamp_range(1..=16));
+        });
+        //选定位宽时按位宽补齐，补齐方式不起作用
+        ui.add_enabled_ui(settings.bit_width.is_none(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label(t("前导零补齐"));
+                ui.selectable_value(&mut settings.padding, Padding::None, t("不补齐"));
+                ui.selectable_value(&mut settings.padding, Padding::Byte, t("整字节"));
+                ui.selectable_value(&mut settings.padding, Padding::Fixed, t("固定位宽"));
+                if settings.padding == Padding::Fixed {
+                    ui.add(DragValue::new(&mut settings.pad_bits).clamp_range(1..=64).suffix(t("位")));
+                }
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("代码字面量"));
+            ui.selectable_value(&mut settings.literal_language, LiteralLanguage::Rust, "Rust");
+            ui.selectable_value(&mut settings.literal_language, LiteralLanguage::C, "C");
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("浮点数显示"));
+            ui.selectable_value(&mut settings.float_format, FloatFormat::Auto, t("自动"));
+            ui.selectable_value(&mut settings.float_format, FloatFormat::Fixed, t("小数位数"));
+            ui.selectable_value(&mut settings.float_format, FloatFormat::Significant, t("有效数字"));
+            if settings.float_format != FloatFormat::Auto {
+                ui.add(DragValue::new(&mut settings.float_digits).clamp_range(1..=17));
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("特殊值显示"));
+            ui.selectable_value(&mut settings.friendly_special_values, true, t("友好"))
+                .on_hover_text(t("NaN和无穷大显示为带说明的文字"));
+            ui.selectable_value(&mut settings.friendly_special_values, false, t("原始"))
+                .on_hover_text(t("与Rust的输出一致，如NaN、inf、-inf"));
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("单位换算小数位数"));
+            ui.add(DragValue::new(&mut settings.unit_precision).clamp_range(0..=15));
+        });
+        ui.horizontal(|ui| {
+            ui.label(t("随机种子"));
+            //按文本编辑并解析为u64，DragValue按f64编辑会改变超过2^53的种子；编辑中的文本存放在egui的临时数据中
+            let seed_id = ui.id().with("随机种子");
+            let mut text = ui.data(|d| d.get_temp::<String>(seed_id)).unwrap_or_else(|| settings.random_seed.to_string());
+            ui.add(TextEdit::singleline(&mut text).desired_width(180.0));
+            let parsed = text.trim().parse::<u64>();
+            if let Ok(seed) = parsed {
+                settings.random_seed = seed;
+            }
+            if ui.add_enabled(parsed.is_ok(), Button::new(t("应用种子"))).clicked() {
+                seed_random(settings.random_seed);
+            }
+            if parsed.is_err() {
+                ui.colored_label(Color32::RED, t("种子须为u64范围内的整数"));
+            }
+            ui.data_mut(|d| d.insert_temp(seed_id, text));
+        });
+        //点击后需要再次确认，确认状态存放在egui的临时数据中
+        let confirm_id = ui.id().with("恢复默认设置");
+        let mut confirming = ui.data(|d| d.get_temp::<bool>(confirm_id)).unwrap_or(false);
+        ui.horizontal(|ui| {
+            if !confirming {
+                confirming = ui.button(t("恢复默认设置")).clicked();
+                return;
+            }
+            ui.label(t("确定恢复所有设置的默认值？"));
+            if ui.button(t("确定")).clicked() {
+                settings.reset_to_defaults();
+                confirming = false;
+                reset = true;
+            }
+            if ui.button(t("取消")).clicked() {
+                confirming = false;
+            }
+        });
+        ui.data_mut(|d| d.insert_temp(confirm_id, confirming));
+    });
+    reset
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grouping_follows_group_output() {
+        let mut settings = Settings { group_separator: ' ', ..Settings::default() };
+        assert_eq!(settings.grouping(2), (4, ' '));
+        assert_eq!(settings.group("11110000", 2), "1111 0000");
+        assert_eq!(settings.group("1234567", 10), "1 234 567");
+        settings.group_output = false;
+        assert_eq!(settings.grouping(2), (0, ' '));
+        for radix in [2, 8, 10, 16] {
+            assert_eq!(settings.group("11110000", radix), "11110000");
+        }
+    }
+
+    #[test]
+    fn prefix_on_copy_drops_separators() {
+        let mut settings = Settings { group_separator: ' ', prefix_on_copy: true, ..Settings::default() };
+        assert_eq!(settings.copy_prefix(&settings.group("ffff", 16), 16), "0xffff");
+        assert_eq!(settings.copy_prefix(&settings.group("-1111", 2), 2), "-0b1111");
+        assert_eq!(settings.copy_prefix(&settings.group("1234567", 10), 10), "1 234 567");
+        settings.prefix_on_copy = false;
+        assert_eq!(settings.copy_prefix(&settings.group("ffff", 16), 16), "ffff");
+    }
+
+    #[test]
+    fn reset_restores_defaults_and_keeps_seed() {
+        let mut settings = Settings::new();
+        let seed = settings.random_seed;
+        settings.hex_uppercase = true;
+        settings.group_output = false;
+        settings.bit_width = Some(16);
+        settings.language = Language::English;
+        settings.reset_to_defaults();
+        assert_eq!(settings, Settings { random_seed: seed, ..Settings::default() });
+    }
+}
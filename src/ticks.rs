@@ -0,0 +1,75 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+use crate::units::*;
+
+//时长换算为定时器节拍数，左边是整数时反过来把节拍数换算为时长
+enum TickResult {
+    Ticks { ticks: u64, exact: f64, rounded: bool },
+    Duration(f64),
+    OutOfRange,
+}
+
+pub fn ticks(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut output = None;
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("时长与节拍换算")).color(Color32::BLUE)).on_hover_text(t("格式如\"1.5ms @ 72MHz\"，左边为整数节拍数时换算为时长；时长单位ns/us/ms/s，频率单位mHz/Hz/kHz/MHz/GHz，词头区分大小写"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+        example_menu(data, "时长与节拍换算", ui);
+
+        if data.input_data.trim().is_empty() {
+            data.set_data_error(DataError::LenNull);
+            return;
+        }
+        output = data.input_data.split_once('@').and_then(|(left, right)| {
+            let hz = parse_frequency(right).filter(|&hz| hz > 0.0)?;
+            if let Some(seconds) = parse_duration(left) {
+                return Some(match duration_to_ticks(seconds, hz) {
+                    Some((ticks, rounded)) => TickResult::Ticks { ticks, exact: seconds * hz, rounded },
+                    None => TickResult::OutOfRange,
+                });
+            }
+            //节拍数可带0x等前缀
+            let (radix, digits) = detect_number_format(left)?;
+            parse_radix(&digits, radix).ok().map(|ticks| TickResult::Duration(ticks_to_duration(ticks, hz)))
+        });
+        if output.is_none() {
+            data.set_data_error(DataError::FormatError);
+        }
+    });
+    ui.horizontal(|ui| {
+        match (data.get_data_error(), output) {
+            (DataError::LenNull, _) => {
+                ui.colored_label(Color32::RED, t("请输入数值"));
+            }
+            (_, Some(TickResult::Ticks { ticks, exact, rounded })) => {
+                data.set_output_data(ticks.to_string());
+                ui.add(Label::new(RichText::new(t("节拍数:")).color(Color32::BLUE)));
+                copyable(ui, &settings.group(&data.output_data, 10));
+                ui.separator();
+                ui.add(Label::new(RichText::new(t("16进制:")).color(Color32::BLUE)));
+                copyable(ui, &format!("0x{}", settings.hex_case(to_radix(ticks, 16))));
+                if rounded {
+                    ui.colored_label(Color32::GRAY, t("精确值{}，已四舍五入").replace("{}", &exact.to_string()));
+                }
+            }
+            (_, Some(TickResult::Duration(seconds))) => {
+                data.set_output_data(format_duration(seconds));
+                ui.add(Label::new(RichText::new(t("时长:")).color(Color32::BLUE)));
+                copyable(ui, &data.output_data);
+            }
+            (_, Some(TickResult::OutOfRange)) => {
+                data.set_data_error(DataError::LenOver);
+                ui.colored_label(Color32::RED, t("节拍数超出u64范围"));
+            }
+            (_, None) => {
+                ui.colored_label(Color32::RED, t("请按\"时长 @ 频率\"或\"节拍数 @ 频率\"的格式输入"));
+            }
+        }
+    });
+}
@@ -0,0 +1,99 @@
+//! 64位整数按各种时间戳格式解释为UTC时间
+
+/// 时间戳格式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimestampFormat {
+    /// 1970-01-01起的秒数
+    UnixSeconds,
+    /// 1970-01-01起的毫秒数
+    UnixMillis,
+    /// Windows FILETIME，1601-01-01起的100纳秒数
+    FileTime,
+    /// NTP时间戳，高32位为1900-01-01起的秒数，低32位为秒的小数部分
+    Ntp,
+}
+
+impl TimestampFormat {
+    pub const ALL: [TimestampFormat; 4] = [
+        TimestampFormat::UnixSeconds,
+        TimestampFormat::UnixMillis,
+        TimestampFormat::FileTime,
+        TimestampFormat::Ntp,
+    ];
+}
+
+//1601-01-01和1900-01-01到1970-01-01的秒数
+const FILETIME_EPOCH_OFFSET: i64 = 11_644_473_600;
+const NTP_EPOCH_OFFSET: i64 = 2_208_988_800;
+//能显示的范围为0001-01-01至9999-12-31
+const MIN_UNIX_SECONDS: i64 = -62_135_596_800;
+const MAX_UNIX_SECONDS: i64 = 253_402_300_799;
+
+/// 按格式换算为Unix时间(秒, 纳秒)，超出0001~9999年时返回None
+///
+/// ```
+/// use number_conversion::timestamp::{to_unix, TimestampFormat};
+/// assert_eq!(to_unix(1_700_000_000, TimestampFormat::UnixSeconds), Some((1_700_000_000, 0)));
+/// assert_eq!(to_unix(1_700_000_000_123, TimestampFormat::UnixMillis), Some((1_700_000_000, 123_000_000)));
+/// assert_eq!(to_unix(116_444_736_000_000_000, TimestampFormat::FileTime), Some((0, 0)));
+/// assert_eq!(to_unix(0, TimestampFormat::FileTime), Some((-11_644_473_600, 0)));
+/// assert_eq!(to_unix(0x83AA_7E80_8000_0000, TimestampFormat::Ntp), Some((0, 500_000_000)));
+/// assert_eq!(to_unix(u64::MAX, TimestampFormat::UnixSeconds), None);
+/// ```
+pub fn to_unix(value: u64, format: TimestampFormat) -> Option<(i64, u32)> {
+    let (seconds, nanos) = match format {
+        TimestampFormat::UnixSeconds => (i64::try_from(value).ok()?, 0),
+        TimestampFormat::UnixMillis => ((value / 1000) as i64, (value % 1000) as u32 * 1_000_000),
+        TimestampFormat::FileTime => {
+            ((value / 10_000_000) as i64 - FILETIME_EPOCH_OFFSET, (value % 10_000_000) as u32 * 100)
+        }
+        TimestampFormat::Ntp => {
+            //小数部分为fraction/2^32秒
+            let fraction = value & 0xFFFF_FFFF;
+            ((value >> 32) as i64 - NTP_EPOCH_OFFSET, ((fraction * 1_000_000_000) >> 32) as u32)
+        }
+    };
+    (MIN_UNIX_SECONDS..=MAX_UNIX_SECONDS).contains(&seconds).then_some((seconds, nanos))
+}
+
+/// Unix时间格式化为"YYYY-MM-DD HH:MM:SS UTC"，有小数秒时去掉末尾的0后附在秒后面
+///
+/// ```
+/// use number_conversion::timestamp::format_utc;
+/// assert_eq!(format_utc(0, 0), "1970-01-01 00:00:00 UTC");
+/// assert_eq!(format_utc(1_700_000_000, 123_000_000), "2023-11-14 22:13:20.123 UTC");
+/// assert_eq!(format_utc(-11_644_473_600, 0), "1601-01-01 00:00:00 UTC");
+/// assert_eq!(format_utc(951_782_400, 0), "2000-02-29 00:00:00 UTC");
+/// ```
+pub fn format_utc(seconds: i64, nanos: u32) -> String {
+    let days = seconds.div_euclid(86_400);
+    let time = seconds.rem_euclid(86_400);
+    let (year, month, day) = civil_from_days(days);
+    let mut text = format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    );
+    if nanos > 0 {
+        text += format!(".{:09}", nanos).trim_end_matches('0');
+    }
+    text + " UTC"
+}
+
+//1970-01-01起的天数换算为公历(年, 月, 日)，即Howard Hinnant的civil_from_days算法
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
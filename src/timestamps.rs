@@ -0,0 +1,62 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+use crate::timestamp::*;
+
+//把一个64位整数同时按Unix秒、Unix毫秒、FILETIME和NTP时间戳解释
+pub fn timestamps(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut value = None;
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("时间戳解析")).color(Color32::BLUE)).on_hover_text(t("输入10进制或带0x前缀的16进制整数，按各种时间戳格式显示为UTC时间"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+        example_menu(data, "时间戳解析", ui);
+
+        if data.input_data.trim().is_empty() {
+            data.set_data_error(DataError::LenNull);
+            return;
+        }
+        match detect_number_format(&data.input_data) {
+            Some((radix, digits)) => match parse_radix(&digits, radix) {
+                Ok(number) => value = Some(number),
+                Err(_) => data.set_data_error(DataError::LenOver),
+            },
+            None => data.set_data_error(DataError::FormatError),
+        }
+    });
+    let Some(value) = value else {
+        match data.get_data_error() {
+            DataError::LenNull => ui.colored_label(Color32::RED, t("请输入数值")),
+            DataError::LenOver => ui.colored_label(Color32::RED, t("数值大于u64最大值:{}").replace("{}", &u64::MAX.to_string())),
+            _ => ui.colored_label(Color32::RED, t("无法识别进制，请使用对应的进制输入框")),
+        };
+        return;
+    };
+    let mut converted = Vec::new();
+    for format in TimestampFormat::ALL {
+        ui.horizontal(|ui| {
+            let label = match format {
+                TimestampFormat::UnixSeconds => t("Unix秒:"),
+                TimestampFormat::UnixMillis => t("Unix毫秒:"),
+                TimestampFormat::FileTime => "FILETIME:",
+                TimestampFormat::Ntp => "NTP:",
+            };
+            ui.add(Label::new(RichText::new(label).color(Color32::BLUE)));
+            match to_unix(value, format) {
+                Some((seconds, nanos)) => {
+                    let text = format_utc(seconds, nanos);
+                    copyable(ui, &text);
+                    converted.push(text);
+                }
+                None => {
+                    ui.colored_label(Color32::GRAY, t("超出0001~9999年"));
+                }
+            }
+        });
+    }
+    data.set_output_data(converted.join("\n"));
+}
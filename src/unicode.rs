@@ -0,0 +1,190 @@
+//! 字符与Unicode码点、UTF-8、UTF-16编码之间的查询
+
+//常用符号的Unicode名称，按名称片段查询时使用
+const CHAR_NAMES: &[(&str, char)] = &[
+    ("SPACE", ' '),
+    ("LATIN CAPITAL LETTER A", 'A'),
+    ("LATIN SMALL LETTER A", 'a'),
+    ("DIGIT ZERO", '0'),
+    ("LATIN SMALL LETTER E WITH ACUTE", 'é'),
+    ("LATIN SMALL LETTER U WITH DIAERESIS", 'ü'),
+    ("LATIN SMALL LETTER SHARP S", 'ß'),
+    ("LATIN SMALL LETTER N WITH TILDE", 'ñ'),
+    ("COPYRIGHT SIGN", '©'),
+    ("REGISTERED SIGN", '®'),
+    ("DEGREE SIGN", '°'),
+    ("PLUS-MINUS SIGN", '±'),
+    ("MICRO SIGN", 'µ'),
+    ("MULTIPLICATION SIGN", '×'),
+    ("DIVISION SIGN", '÷'),
+    ("GREEK SMALL LETTER ALPHA", 'α'),
+    ("GREEK SMALL LETTER BETA", 'β'),
+    ("GREEK SMALL LETTER PI", 'π'),
+    ("GREEK CAPITAL LETTER OMEGA", 'Ω'),
+    ("EN DASH", '–'),
+    ("EM DASH", '—'),
+    ("HORIZONTAL ELLIPSIS", '…'),
+    ("EURO SIGN", '€'),
+    ("YEN SIGN", '¥'),
+    ("POUND SIGN", '£'),
+    ("LEFTWARDS ARROW", '←'),
+    ("RIGHTWARDS ARROW", '→'),
+    ("INFINITY", '∞'),
+    ("NOT EQUAL TO", '≠'),
+    ("LESS-THAN OR EQUAL TO", '≤'),
+    ("GREATER-THAN OR EQUAL TO", '≥'),
+    ("SQUARE ROOT", '√'),
+    ("CHECK MARK", '✓'),
+    ("CJK UNIFIED IDEOGRAPH-4E2D", '中'),
+    ("REPLACEMENT CHARACTER", '\u{FFFD}'),
+    ("GRINNING FACE", '😀'),
+    ("CRAB", '🦀'),
+];
+
+/// 单个字符的码点及其UTF-8、UTF-16编码
+pub struct CharInfo {
+    pub ch: char,
+    pub code_point: u32,
+    pub utf8: Vec<u8>,
+    pub utf16: Vec<u16>,
+}
+
+impl CharInfo {
+    /// ```
+    /// use number_conversion::unicode::CharInfo;
+    /// let info = CharInfo::new('A');
+    /// assert_eq!(info.code_point, 0x41);
+    /// assert_eq!(info.utf8, vec![0x41]);
+    /// assert_eq!(info.utf16, vec![0x0041]);
+    /// assert_eq!(info.name(), Some("LATIN CAPITAL LETTER A"));
+    ///
+    /// let info = CharInfo::new('é');
+    /// assert_eq!(info.code_point, 0xE9);
+    /// assert_eq!(info.utf8, vec![0xC3, 0xA9]);
+    /// assert_eq!(info.utf16, vec![0x00E9]);
+    /// ```
+    pub fn new(ch: char) -> CharInfo {
+        let mut utf8 = [0u8; 4];
+        let mut utf16 = [0u16; 2];
+        CharInfo {
+            ch,
+            code_point: ch as u32,
+            utf8: ch.encode_utf8(&mut utf8).as_bytes().to_vec(),
+            utf16: ch.encode_utf16(&mut utf16).to_vec(),
+        }
+    }
+
+    /// 内置名称表中的名称，不在表中时返回None
+    pub fn name(&self) -> Option<&'static str> {
+        CHAR_NAMES
+            .iter()
+            .find(|(_, ch)| *ch == self.ch)
+            .map(|(name, _)| *name)
+    }
+}
+
+/// 按名称片段查找字符，不区分大小写，返回全部匹配项
+///
+/// ```
+/// use number_conversion::unicode::lookup_name;
+/// let found = lookup_name("acute");
+/// assert_eq!(found, vec![("LATIN SMALL LETTER E WITH ACUTE", 'é')]);
+/// assert_eq!(lookup_name("capital letter a"), vec![("LATIN CAPITAL LETTER A", 'A')]);
+/// ```
+pub fn lookup_name(fragment: &str) -> Vec<(&'static str, char)> {
+    let fragment = fragment.trim().to_uppercase();
+    if fragment.is_empty() {
+        return Vec::new();
+    }
+    CHAR_NAMES
+        .iter()
+        .filter(|(name, _)| name.contains(&fragment))
+        .copied()
+        .collect()
+}
+
+/// UTF-16解码失败的原因
+#[derive(Debug, PartialEq)]
+pub enum Utf16Error {
+    /// 含有非16进制字符
+    InvalidHex,
+    /// 字节数不是2的倍数
+    OddLength,
+    /// 出现未配对的代理项
+    LoneSurrogate,
+}
+
+/// 把Windows换行"\r\n"统一为"\n"，单独的"\r"保留
+///
+/// ```
+/// use number_conversion::unicode::normalize_newlines;
+/// assert_eq!(normalize_newlines("a\r\nb\r\n"), "a\nb\n");
+/// assert_eq!(normalize_newlines("a\rb\n"), "a\rb\n");
+///
+/// // 不统一时"\r\n"原样编码为两个代码单元
+/// use number_conversion::unicode::utf16_encode;
+/// assert_eq!(utf16_encode("\r\n", false), "000D 000A");
+/// assert_eq!(utf16_encode(&normalize_newlines("\r\n"), false), "000A");
+/// ```
+pub fn normalize_newlines(text: &str) -> String {
+    text.replace("\r\n", "\n")
+}
+
+/// 文本编码为UTF-16代码单元的16进制串，代码单元之间以空格分隔
+///
+/// 不写入BOM；U+10000以上的字符编码为代理对
+///
+/// ```
+/// use number_conversion::unicode::utf16_encode;
+/// assert_eq!(utf16_encode("A", false), "0041");
+/// assert_eq!(utf16_encode("A", true), "4100");
+/// assert_eq!(utf16_encode("🦀", false), "D83E DD80");
+/// ```
+pub fn utf16_encode(text: &str, little_endian: bool) -> String {
+    text.encode_utf16()
+        .map(|unit| {
+            let bytes = if little_endian {
+                unit.to_le_bytes()
+            } else {
+                unit.to_be_bytes()
+            };
+            format!("{:02X}{:02X}", bytes[0], bytes[1])
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// UTF-16代码单元的16进制串解码为文本，忽略空格和下划线
+///
+/// 不解释BOM，开头的FEFF会解码为U+FEFF字符
+///
+/// ```
+/// use number_conversion::unicode::{utf16_decode, Utf16Error};
+/// assert_eq!(utf16_decode("D83E DD80", false), Ok("🦀".to_string()));
+/// assert_eq!(utf16_decode("3ED8 80DD", true), Ok("🦀".to_string()));
+/// assert_eq!(utf16_decode("D83E", false), Err(Utf16Error::LoneSurrogate));
+/// ```
+pub fn utf16_decode(hex: &str, little_endian: bool) -> Result<String, Utf16Error> {
+    let digits: Vec<u32> = hex
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '_')
+        .map(|c| c.to_digit(16).ok_or(Utf16Error::InvalidHex))
+        .collect::<Result<_, _>>()?;
+    if !digits.len().is_multiple_of(4) {
+        return Err(Utf16Error::OddLength);
+    }
+    let units: Vec<u16> = digits
+        .chunks(4)
+        .map(|d| {
+            let bytes = [(d[0] * 16 + d[1]) as u8, (d[2] * 16 + d[3]) as u8];
+            if little_endian {
+                u16::from_le_bytes(bytes)
+            } else {
+                u16::from_be_bytes(bytes)
+            }
+        })
+        .collect();
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|_| Utf16Error::LoneSurrogate)
+}
@@ -0,0 +1,48 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::units::*;
+
+pub fn unit_convert(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut output = None;
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("单位换算")).color(Color32::BLUE)).on_hover_text(
+            t("格式如\"1 km -> m\"，支持单位:{}").replace("{}", &unit_names().collect::<Vec<_>>().join(" ")),
+        );
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+        example_menu(data, "单位换算", ui);
+
+        if data.input_data.trim().is_empty() {
+            data.set_data_error(DataError::LenNull);
+            return;
+        }
+        match parse_unit_query(&data.input_data) {
+            Some((value, from, to)) => output = Some((convert_unit(value, from, to), to.to_string())),
+            None => data.set_data_error(DataError::FormatError),
+        }
+    });
+    ui.horizontal(|ui| {
+        match (data.get_data_error(), output) {
+            (DataError::LenNull, _) => ui.colored_label(Color32::RED, t("请输入数值")),
+            (_, Some((Ok(value), to))) => {
+                    //按设置的小数位数输出，去掉末尾多余的0
+                    let string_data = format!("{:.*}", settings.unit_precision, value);
+                    let string_data = if string_data.contains('.') {
+                        string_data.trim_end_matches('0').trim_end_matches('.').to_string()
+                    } else {
+                        string_data
+                    };
+                    data.set_output_data(string_data);
+                    copyable(ui, &data.output_data);
+                    ui.monospace(to)
+            }
+            (_, Some((Err(UnitError::UnknownUnit(unit)), _))) => ui.colored_label(Color32::RED, t("未知单位:{}").replace("{}", &unit)),
+            (_, Some((Err(UnitError::Incompatible(..)), _))) => ui.colored_label(Color32::RED, t("单位类别不同，无法换算")),
+            (_, None) => ui.colored_label(Color32::RED, t("请按\"数值 单位 -> 单位\"的格式输入")),
+        }
+    });
+}
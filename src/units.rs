@@ -0,0 +1,264 @@
+//! 单位换算：长度、质量、温度和数据大小，以及时长与时钟节拍的换算
+
+/// 单位所属的类别，只有同类单位之间可以换算
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnitCategory {
+    Length,
+    Mass,
+    Temperature,
+    DataSize,
+}
+
+/// 单位换算失败的原因
+#[derive(Debug, PartialEq)]
+pub enum UnitError {
+    /// 不认识的单位名称
+    UnknownUnit(String),
+    /// 两个单位不属于同一类别
+    Incompatible(UnitCategory, UnitCategory),
+}
+
+//(名称, 类别, 换算到基准单位的系数)，基准单位为米、千克、开尔文和字节
+//温度的系数不使用，按仿射变换单独处理
+const UNITS: &[(&str, UnitCategory, f64)] = &[
+    ("nm", UnitCategory::Length, 1e-9),
+    ("um", UnitCategory::Length, 1e-6),
+    ("mm", UnitCategory::Length, 1e-3),
+    ("cm", UnitCategory::Length, 1e-2),
+    ("m", UnitCategory::Length, 1.0),
+    ("km", UnitCategory::Length, 1e3),
+    ("in", UnitCategory::Length, 0.0254),
+    ("ft", UnitCategory::Length, 0.3048),
+    ("yd", UnitCategory::Length, 0.9144),
+    ("mi", UnitCategory::Length, 1609.344),
+    ("mg", UnitCategory::Mass, 1e-6),
+    ("g", UnitCategory::Mass, 1e-3),
+    ("kg", UnitCategory::Mass, 1.0),
+    ("t", UnitCategory::Mass, 1e3),
+    ("oz", UnitCategory::Mass, 0.028349523125),
+    ("lb", UnitCategory::Mass, 0.45359237),
+    ("C", UnitCategory::Temperature, 1.0),
+    ("F", UnitCategory::Temperature, 1.0),
+    ("K", UnitCategory::Temperature, 1.0),
+    ("bit", UnitCategory::DataSize, 0.125),
+    ("B", UnitCategory::DataSize, 1.0),
+    ("bytes", UnitCategory::DataSize, 1.0),
+    ("KB", UnitCategory::DataSize, 1e3),
+    ("MB", UnitCategory::DataSize, 1e6),
+    ("GB", UnitCategory::DataSize, 1e9),
+    ("TB", UnitCategory::DataSize, 1e12),
+    ("KiB", UnitCategory::DataSize, 1024.0),
+    ("MiB", UnitCategory::DataSize, 1_048_576.0),
+    ("GiB", UnitCategory::DataSize, 1_073_741_824.0),
+    ("TiB", UnitCategory::DataSize, 1_099_511_627_776.0),
+];
+
+fn find_unit(name: &str) -> Result<(UnitCategory, f64), UnitError> {
+    UNITS
+        .iter()
+        .find(|(unit, _, _)| *unit == name)
+        .map(|&(_, category, factor)| (category, factor))
+        .ok_or_else(|| UnitError::UnknownUnit(name.to_string()))
+}
+
+//温度先换算为开尔文
+fn to_kelvin(value: f64, unit: &str) -> f64 {
+    match unit {
+        "C" => value + 273.15,
+        "F" => (value - 32.0) * 5.0 / 9.0 + 273.15,
+        _ => value,
+    }
+}
+
+fn from_kelvin(value: f64, unit: &str) -> f64 {
+    match unit {
+        "C" => value - 273.15,
+        "F" => (value - 273.15) * 9.0 / 5.0 + 32.0,
+        _ => value,
+    }
+}
+
+/// 所有支持的单位名称，按类别排列
+///
+/// ```
+/// use number_conversion::units::unit_names;
+/// assert!(unit_names().any(|name| name == "MiB"));
+/// ```
+pub fn unit_names() -> impl Iterator<Item = &'static str> {
+    UNITS.iter().map(|(unit, _, _)| *unit)
+}
+
+/// 在同类单位之间换算，单位名称区分大小写
+///
+/// ```
+/// use number_conversion::units::{convert_unit, UnitCategory, UnitError};
+/// assert_eq!(convert_unit(1.0, "km", "m"), Ok(1000.0));
+/// assert!((convert_unit(100.0, "C", "F").unwrap() - 212.0).abs() < 1e-9);
+/// assert_eq!(convert_unit(1.0, "MiB", "bytes"), Ok(1_048_576.0));
+/// assert_eq!(convert_unit(1.0, "km", "kg"), Err(UnitError::Incompatible(UnitCategory::Length, UnitCategory::Mass)));
+/// assert_eq!(convert_unit(1.0, "furlong", "m"), Err(UnitError::UnknownUnit("furlong".to_string())));
+/// ```
+pub fn convert_unit(value: f64, from: &str, to: &str) -> Result<f64, UnitError> {
+    let (from_category, from_factor) = find_unit(from)?;
+    let (to_category, to_factor) = find_unit(to)?;
+    if from_category != to_category {
+        return Err(UnitError::Incompatible(from_category, to_category));
+    }
+    if from_category == UnitCategory::Temperature {
+        return Ok(from_kelvin(to_kelvin(value, from), to));
+    }
+    Ok(value * from_factor / to_factor)
+}
+
+/// 解析"数值 单位 -> 单位"形式的输入，返回(数值, 源单位, 目标单位)
+///
+/// ```
+/// use number_conversion::units::parse_unit_query;
+/// assert_eq!(parse_unit_query("1.5 km -> m"), Some((1.5, "km", "m")));
+/// assert_eq!(parse_unit_query("100C->F"), Some((100.0, "C", "F")));
+/// assert_eq!(parse_unit_query("1e3m -> km"), Some((1000.0, "m", "km")));
+/// assert_eq!(parse_unit_query("1 km"), None);
+/// ```
+pub fn parse_unit_query(input: &str) -> Option<(f64, &str, &str)> {
+    let (left, to) = input.split_once("->")?;
+    let (value, from) = split_value_unit(left)?;
+    Some((value, from, to.trim()))
+}
+
+//拆分"数值 单位"，数值和单位之间的空格可省略，取能解析为数值的最长前缀，以支持1e3m这样的输入
+fn split_value_unit(input: &str) -> Option<(f64, &str)> {
+    let input = input.trim();
+    let (split, value) = input
+        .char_indices()
+        .rev()
+        .filter(|&(i, c)| i > 0 && c.is_alphabetic())
+        .find_map(|(i, _)| input[..i].trim().replace('_', "").parse::<f64>().ok().map(|value| (i, value)))?;
+    Some((value, input[split..].trim()))
+}
+
+/// 把字节数格式化为带单位的大小，binary为true时按1024进位(KiB/MiB/...)，否则按1000进位(KB/MB/...)
+///
+/// ```
+/// use number_conversion::units::format_byte_size;
+/// assert_eq!(format_byte_size(1024, true), "1 KiB");
+/// assert_eq!(format_byte_size(1000, false), "1 KB");
+/// assert_eq!(format_byte_size(1536, true), "1.5 KiB");
+/// assert_eq!(format_byte_size(999, false), "999 B");
+/// ```
+pub fn format_byte_size(bytes: u64, binary: bool) -> String {
+    let (base, units) = if binary {
+        (1024.0, ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"])
+    } else {
+        (1000.0, ["B", "KB", "MB", "GB", "TB", "PB", "EB"])
+    };
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= base && unit + 1 < units.len() {
+        value /= base;
+        unit += 1;
+    }
+    if unit == 0 {
+        return format!("{} B", bytes);
+    }
+    //最多保留两位小数
+    let number = format!("{:.2}", value);
+    let number = number.trim_end_matches('0').trim_end_matches('.');
+    format!("{} {}", number, units[unit])
+}
+
+//时长的SI词头和换算到秒的系数
+const DURATION_PREFIXES: &[(&str, f64)] = &[("n", 1e-9), ("u", 1e-6), ("µ", 1e-6), ("m", 1e-3), ("", 1.0)];
+//频率的SI词头和换算到Hz的系数
+const FREQUENCY_PREFIXES: &[(&str, f64)] = &[("m", 1e-3), ("", 1.0), ("k", 1e3), ("M", 1e6), ("G", 1e9)];
+
+//单位字母不区分大小写，词头区分大小写，否则mHz和MHz无法区分
+fn parse_scaled(input: &str, unit_name: &str, prefixes: &[(&str, f64)]) -> Option<f64> {
+    let (value, unit) = split_value_unit(input)?;
+    let split = unit.len().checked_sub(unit_name.len())?;
+    let prefix = unit.get(..split)?;
+    if !unit[split..].eq_ignore_ascii_case(unit_name) {
+        return None;
+    }
+    prefixes
+        .iter()
+        .find(|(name, _)| *name == prefix)
+        .map(|(_, factor)| value * factor)
+}
+
+/// 解析带单位(ns/us/ms/s)的时长，返回秒数，词头区分大小写，单位字母不区分
+///
+/// ```
+/// use number_conversion::units::parse_duration;
+/// assert_eq!(parse_duration("1.5ms"), Some(0.0015));
+/// assert!((parse_duration("250 ns").unwrap() - 250e-9).abs() < 1e-18);
+/// assert_eq!(parse_duration("2 S"), Some(2.0));
+/// assert_eq!(parse_duration("1.5Ms"), None);
+/// assert_eq!(parse_duration("3 min"), None);
+/// assert_eq!(parse_duration("10"), None);
+/// ```
+pub fn parse_duration(input: &str) -> Option<f64> {
+    parse_scaled(input, "s", DURATION_PREFIXES)
+}
+
+/// 解析带单位(mHz/Hz/kHz/MHz/GHz)的频率，返回Hz，词头区分大小写，单位字母不区分
+///
+/// ```
+/// use number_conversion::units::parse_frequency;
+/// assert_eq!(parse_frequency("72MHz"), Some(72e6));
+/// assert_eq!(parse_frequency("32.768 khz"), Some(32768.0));
+/// assert_eq!(parse_frequency("72M"), None);
+/// assert_eq!(parse_frequency("1mHz"), Some(1e-3));
+/// assert_eq!(parse_frequency("1MHZ"), Some(1e6));
+/// ```
+pub fn parse_frequency(input: &str) -> Option<f64> {
+    parse_scaled(input, "Hz", FREQUENCY_PREFIXES)
+}
+
+/// 按时钟频率把时长换算为节拍数，四舍五入到整数，同时返回是否发生了舍入；超出u64或为负时返回None
+///
+/// ```
+/// use number_conversion::units::{duration_to_ticks, parse_duration, parse_frequency};
+/// let ticks = duration_to_ticks(parse_duration("1ms").unwrap(), parse_frequency("72MHz").unwrap());
+/// assert_eq!(ticks, Some((72000, false)));
+/// assert_eq!(duration_to_ticks(1e-6, 1.5e6), Some((2, true)));
+/// assert_eq!(duration_to_ticks(-1.0, 1e6), None);
+/// ```
+pub fn duration_to_ticks(seconds: f64, hz: f64) -> Option<(u64, bool)> {
+    let exact = seconds * hz;
+    if !(0.0..=u64::MAX as f64).contains(&exact) {
+        return None;
+    }
+    let rounded = exact.round();
+    //相对误差在浮点精度以内时不算舍入，避免0.001 * 72e6这样的结果被误报
+    let inexact = (exact - rounded).abs() > rounded.max(1.0) * 1e-9;
+    Some((rounded as u64, inexact))
+}
+
+/// 节拍数按时钟频率换算为秒数
+///
+/// ```
+/// use number_conversion::units::ticks_to_duration;
+/// assert_eq!(ticks_to_duration(72000, 72e6), 0.001);
+/// ```
+pub fn ticks_to_duration(ticks: u64, hz: f64) -> f64 {
+    ticks as f64 / hz
+}
+
+/// 选用最合适的单位显示时长，最多保留6位小数
+///
+/// ```
+/// use number_conversion::units::format_duration;
+/// assert_eq!(format_duration(0.001), "1 ms");
+/// assert_eq!(format_duration(1.5e-6), "1.5 us");
+/// assert_eq!(format_duration(2.0), "2 s");
+/// assert_eq!(format_duration(0.0), "0 s");
+/// ```
+pub fn format_duration(seconds: f64) -> String {
+    let (unit, factor) = [("s", 1.0), ("ms", 1e-3), ("us", 1e-6), ("ns", 1e-9)]
+        .into_iter()
+        .find(|&(_, factor)| seconds.abs() >= factor)
+        .unwrap_or(("s", 1.0));
+    let number = format!("{:.6}", seconds / factor);
+    let number = number.trim_end_matches('0').trim_end_matches('.');
+    format!("{} {}", number, unit)
+}
@@ -0,0 +1,133 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::widgets::*;
+use crate::settings::*;
+use eframe::egui;
+use egui::*;
+use crate::convert::*;
+
+pub fn universal(data: &mut Data, settings: &Settings, ui: &mut Ui) {
+    data.set_data_error(DataError::Nice);
+    let mut detected = None;
+    let mut lines = None;
+    ui.horizontal(|ui| {
+        ui.label(RichText::from(t("智能输入")).color(Color32::BLUE)).on_hover_text(t("支持0x/0b/0o前缀、h/b/o后缀、u8等类型后缀和10进制数，自动识别进制"));
+        data.has_focus = input_edit(data, settings, ui).has_focus();
+        example_menu(data, "智能输入", ui);
+
+        //只输入了前缀时按未输入完处理，不提示无法识别
+        if data.input_data.trim().is_empty() || is_bare_prefix(&data.input_data) {
+            data.set_data_error(DataError::LenNull);
+            return;
+        }
+        //粘贴多行时逐行识别转换，不把各行拼接成一个数
+        lines = split_lines(&data.input_data).map(|lines| lines.into_iter().map(str::to_string).collect::<Vec<String>>());
+        if lines.is_some() {
+            return;
+        }
+        match detect_number_format(&data.input_data) {
+            Some((radix, digits)) => match parse_radix(&digits, radix) {
+                Ok(number) => detected = Some((radix, number)),
+                Err(_) => data.set_data_error(DataError::LenOver),
+            },
+            None => data.set_data_error(DataError::FormatError),
+        }
+    });
+    if let Some(lines) = lines {
+        //有一行无法转换时整批视为无效，不更新结果
+        match batch_universal(&lines, settings, ui) {
+            Some(string_data) => data.set_output_data(string_data),
+            None => data.set_data_error(DataError::FormatError),
+        }
+        return;
+    }
+    ui.horizontal(|ui| {
+        match (data.get_data_error(), detected) {
+            (DataError::LenNull, _) => {
+                ui.colored_label(Color32::RED, t("请输入数值"));
+            }
+            (DataError::LenOver, _) => {
+                ui.colored_label(Color32::RED, t("数值大于u64最大值:{}").replace("{}", &u64::MAX.to_string()));
+            }
+            (_, Some((radix, number))) => {
+                ui.add(Label::new(RichText::new(t("识别为{}进制").replace("{}", &radix.to_string())).color(Color32::GRAY)));
+                let results = radix_outputs(number, settings, ui);
+                data.set_output_data(radix_results(&results.each_ref().map(|(radix, digits)| (*radix, digits.as_str()))));
+            }
+            (_, None) => {
+                ui.colored_label(Color32::RED, t("无法识别进制，请使用对应的进制输入框"));
+            }
+        }
+    });
+}
+
+//逐行转换，每行显示识别的进制和各进制结果，全部转换成功时返回各行的10进制结果
+fn batch_universal(lines: &[String], settings: &Settings, ui: &mut Ui) -> Option<String> {
+    let mut converted = Vec::new();
+    let mut failed = false;
+    for line in lines {
+        ui.horizontal(|ui| {
+            ui.monospace(line);
+            let detected = detect_number_format(line).map(|(radix, digits)| (radix, parse_radix(&digits, radix)));
+            match detected {
+                Some((radix, Ok(number))) => {
+                    ui.label(RichText::new(t("识别为{}进制").replace("{}", &radix.to_string())).color(Color32::GRAY));
+                    let [.., (_, decimal), _] = radix_outputs(number, settings, ui);
+                    converted.push(decimal);
+                }
+                Some((_, Err(_))) => {
+                    failed = true;
+                    ui.colored_label(Color32::RED, t("数值大于u64最大值:{}").replace("{}", &u64::MAX.to_string()));
+                }
+                None => {
+                    failed = true;
+                    ui.colored_label(Color32::RED, t("无法识别进制，请使用对应的进制输入框"));
+                }
+            }
+        });
+    }
+    (!failed).then(|| converted.join("\n"))
+}
+
+//显示一个数的2、8、10、16进制结果，返回按进制排列的未分组数字
+fn radix_outputs(number: u64, settings: &Settings, ui: &mut Ui) -> [(u32, String); 4] {
+    let conversions = BaseConversions::new(number);
+    let results = [
+        (2, settings.pad(conversions.binary, 2)),
+        (8, conversions.octal),
+        (10, conversions.decimal),
+        (16, settings.hex_case(settings.pad(conversions.hex, 16))),
+    ];
+    for ((radix, digits), label) in results.iter().zip(["2进制:", "8进制:", "10进制:", "16进制:"]) {
+        ui.separator();
+        ui.add(Label::new(RichText::new(t(label)).color(Color32::BLUE)));
+        if *radix == 2 {
+            binary_output(ui, digits, settings);
+        } else {
+            radix_copyable(ui, &settings.group(digits, *radix), *radix, settings);
+        }
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(input: &str) -> Data {
+        let mut data = Data::new();
+        data.input_data = input.to_string();
+        run_panel(universal, &mut data, &Settings::default());
+        data
+    }
+
+    #[test]
+    fn bare_prefix_is_unfinished_input() {
+        for input in ["0x", "0b", "0O"] {
+            assert!(convert(input).get_data_error() == &DataError::LenNull, "{}", input);
+        }
+        let data = convert("0xFF");
+        assert!(data.get_data_error() == &DataError::Nice);
+        assert_eq!(data.output_data, "0b11111111 0o377 255 0xff");
+    }
+}
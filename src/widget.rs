@@ -0,0 +1,70 @@
+//! 可嵌入其他egui程序的单个转换框，需要gui特性
+
+use crate::converter::*;
+use eframe::egui::{self, Color32, Label, Response, RichText, TextEdit, Ui};
+
+/// 自带输入内容的转换框，输入框下方显示转换结果或错误
+///
+/// 标题和提示直接使用转换器给出的文字，不经过界面的翻译表
+///
+/// ```
+/// use eframe::egui;
+/// use number_conversion::converter::QpEncode;
+/// use number_conversion::widget::ConverterWidget;
+/// let mut widget = ConverterWidget::new(Box::new(QpEncode));
+/// widget.input = "a=b".to_string();
+/// assert_eq!(widget.output(), Ok("a=3Db".to_string()));
+///
+/// let ctx = egui::Context::default();
+/// let _ = ctx.run(Default::default(), |ctx| {
+///     egui::CentralPanel::default().show(ctx, |ui| widget.ui(ui));
+/// });
+/// ```
+pub struct ConverterWidget {
+    converter: Box<dyn Converter>,
+    pub input: String,
+}
+
+impl ConverterWidget {
+    pub fn new(converter: Box<dyn Converter>) -> ConverterWidget {
+        ConverterWidget { converter, input: String::new() }
+    }
+    pub fn converter(&self) -> &dyn Converter {
+        self.converter.as_ref()
+    }
+    /// 当前输入的转换结果，输入为空时返回转换器的空输入提示
+    pub fn output(&self) -> Result<String, ConvertError> {
+        if self.input.is_empty() {
+            return Err(ConvertError::new(self.converter.empty_message()));
+        }
+        self.converter.convert(&self.input)
+    }
+    /// 绘制转换框，返回输入框的Response
+    pub fn ui(&mut self, ui: &mut Ui) -> Response {
+        let response = ui
+            .horizontal(|ui| {
+                ui.label(RichText::from(self.converter.name()).color(Color32::BLUE)).on_hover_text(self.converter.hint());
+                ui.add(TextEdit::singleline(&mut self.input).desired_width(400.0))
+            })
+            .inner;
+        ui.horizontal(|ui| match self.output() {
+            Ok(text) => {
+                ui.add(Label::new(RichText::new(self.converter.output_label()).color(Color32::BLUE)));
+                ui.monospace(&text);
+                if ui.small_button("📋").clicked() {
+                    ui.output_mut(|o| o.copied_text = text);
+                }
+            }
+            Err(error) => {
+                ui.colored_label(Color32::RED, error.render(error.message));
+            }
+        });
+        response
+    }
+}
+
+impl egui::Widget for &mut ConverterWidget {
+    fn ui(self, ui: &mut Ui) -> Response {
+        ConverterWidget::ui(self, ui)
+    }
+}
@@ -0,0 +1,431 @@
+use crate::i18n::*;
+use crate::data::*;
+use crate::settings::*;
+use eframe::egui;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use egui::*;
+use egui::text::LayoutJob;
+use crate::convert::*;
+use crate::units::*;
+use crate::examples::examples;
+
+//面板的输入框，关闭实时转换时编辑草稿，按回车或点击"转换"后才提交到input_data
+//回车同时把焦点移到下一个输入框，Tab仍按egui默认顺序经过各按钮
+pub fn input_edit(data: &mut Data, settings: &Settings, ui: &mut Ui) -> Response {
+    edit_input(data, settings, None, ui)
+}
+
+//按进制校验的输入框，不属于该进制的字符实时标红
+pub fn radix_input_edit(data: &mut Data, settings: &Settings, radix: u32, ui: &mut Ui) -> Response {
+    edit_input(data, settings, Some(radix), ui)
+}
+
+fn edit_input(data: &mut Data, settings: &Settings, radix: Option<u32>, ui: &mut Ui) -> Response {
+    let live = settings.live_conversion;
+    //历史、交换等按钮直接修改了input_data时同步到草稿
+    if !live && data.input_data != data.committed_input {
+        data.draft_input.clone_from(&data.input_data);
+        data.committed_input.clone_from(&data.input_data);
+    }
+    let buffer = if live { &mut data.input_data } else { &mut data.draft_input };
+    let text_edit = TextEdit::singleline(buffer).desired_width(400.0);
+    let response = match radix {
+        Some(radix) => {
+            let mut layouter = |ui: &Ui, text: &str, wrap_width: f32| {
+                let mut job = radix_layout_job(text, radix, ui);
+                job.wrap.max_width = wrap_width;
+                ui.fonts(|f| f.layout_job(job))
+            };
+            ui.add(text_edit.layouter(&mut layouter))
+        }
+        None => ui.add(text_edit),
+    };
+    //回车后焦点移到页面上的下一个输入框：先做标记，由之后绘制的第一个输入框取走
+    let focus_next_id = Id::new("回车后聚焦的输入框");
+    if ui.data_mut(|d| d.remove_temp::<bool>(focus_next_id)).unwrap_or(false) {
+        response.request_focus();
+    }
+    let entered = response.lost_focus() && ui.input(|i| i.key_pressed(Key::Enter));
+    if entered {
+        ui.data_mut(|d| d.insert_temp(focus_next_id, true));
+    }
+    if live {
+        data.draft_input.clone_from(&data.input_data);
+        data.committed_input.clone_from(&data.input_data);
+    } else {
+        if ui.button(t("转换")).clicked() || entered {
+            data.input_data.clone_from(&data.draft_input);
+            data.committed_input.clone_from(&data.draft_input);
+        }
+    }
+    response
+}
+
+//逐字符着色：进制前缀、类型后缀和"_"与数字一样视为合法，其余字符标红
+fn radix_layout_job(text: &str, radix: u32, ui: &Ui) -> LayoutJob {
+    let font = TextStyle::Body.resolve(ui.style());
+    let mut job = LayoutJob::default();
+    for (c, valid) in text.chars().zip(valid_chars(text, radix)) {
+        let color = if valid { ui.visuals().widgets.inactive.text_color() } else { Color32::RED };
+        job.append(c.encode_utf8(&mut [0; 4]), 0.0, TextFormat::simple(font.clone(), color));
+    }
+    job
+}
+
+//各字符是否合法；前后缀的范围按去掉"_"后的文本计算，与各面板的校验一致
+fn valid_chars(text: &str, radix: u32) -> Vec<bool> {
+    let normalized = text.replace('_', "");
+    let body_start = normalized.len() - strip_radix_prefix(&normalized, radix).len();
+    let body_end = strip_type_suffix(&normalized).len().max(body_start);
+    let mut pos = 0;
+    text.chars()
+        .map(|c| {
+            if c == '_' {
+                return true;
+            }
+            //10进制允许开头的负号，按位宽取补码
+            let sign = pos == 0 && c == '-' && radix == 10;
+            let valid = sign || pos < body_start || pos >= body_end || c.is_digit(radix);
+            pos += c.len_utf8();
+            valid
+        })
+        .collect()
+}
+
+//历史输入下拉菜单，选中后回填到输入框
+pub fn history_menu(data: &mut Data, ui: &mut Ui) {
+    ui.add_enabled_ui(!data.history.is_empty(), |ui| {
+        ui.menu_button(t("历史"), |ui| {
+            let mut selected = None;
+            for input in data.history.iter() {
+                if ui.selectable_label(false, input).clicked() {
+                    selected = Some(input.clone());
+                }
+            }
+            if let Some(input) = selected {
+                data.input_data = input;
+                ui.close_menu();
+            }
+        });
+    });
+}
+
+//示例输入下拉菜单，选中后填入输入框，panel为examples中的面板名称
+pub fn example_menu(data: &mut Data, panel: &str, ui: &mut Ui) {
+    ui.menu_button(t("示例"), |ui| {
+        for (label, input) in examples(panel) {
+            if ui.selectable_label(false, t(label)).on_hover_text(*input).clicked() {
+                data.input_data = input.to_string();
+                ui.close_menu();
+            }
+        }
+    });
+}
+
+//xorshift64的状态，种子为0时xorshift只会输出0，用固定常数代替
+static RANDOM_STATE: AtomicU64 = AtomicU64::new(0x9E37_79B9_7F4A_7C15);
+
+pub fn seed_random(seed: u64) {
+    RANDOM_STATE.store(if seed == 0 { 0x9E37_79B9_7F4A_7C15 } else { seed }, Ordering::Relaxed);
+}
+
+fn random_u64() -> u64 {
+    let mut x = RANDOM_STATE.load(Ordering::Relaxed);
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    RANDOM_STATE.store(x, Ordering::Relaxed);
+    x
+}
+
+//用随机值填充输入框，generate负责把随机数转成该输入框的合法输入
+pub fn random_button(data: &mut Data, ui: &mut Ui, generate: impl FnOnce(u64) -> String) {
+    if ui.button(t("随机")).clicked() {
+        data.input_data = generate(random_u64());
+    }
+}
+
+//绘制一个面板，开启保留上次结果时在面板下方显示上次的有效结果
+pub fn show_panel(data: &mut Data, settings: &Settings, ui: &mut Ui, panel: impl FnOnce(&mut Data, &Settings, &mut Ui)) {
+    panel(data, settings, ui);
+    last_output(data, settings, ui);
+}
+
+//输入无效时显示上次的有效结果，各面板只在转换完全成功时更新output_data
+fn last_output(data: &Data, settings: &Settings, ui: &mut Ui) {
+    let invalid = matches!(data.get_data_error(), DataError::FormatError | DataError::LenOver);
+    if settings.keep_last_output && invalid && !data.output_data.is_empty() {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(t("上次结果:")).color(Color32::GRAY));
+            ui.label(RichText::new(&data.output_data).monospace().color(Color32::GRAY));
+        });
+    }
+}
+
+//同时输出多个进制的面板把各结果合成一个，2、8、16进制带前缀，如"0xff 255"
+pub fn radix_results(results: &[(u32, &str)]) -> String {
+    results.iter().map(|(radix, digits)| with_radix_prefix(digits, *radix)).collect::<Vec<String>>().join(" ")
+}
+
+//把转换结果填入配对的反向转换输入框，结果无效时不可用
+pub fn swap_button(from: &Data, to: &mut Data, ui: &mut Ui) {
+    let enabled = from.get_data_error() == &DataError::Nice;
+    if ui
+        .add_enabled(enabled, Button::new("⇄").small())
+        .on_hover_text(t("将结果填入反向转换的输入框"))
+        .clicked()
+    {
+        to.input_data = from.output_data.clone();
+        to.set_data_error(DataError::Nice);
+    }
+}
+
+//10进制输入以0开头时提示C语言的8进制含义，可一键填入8进制输入框，10进制转换照常进行
+pub fn octal_hint(decimal: &Data, octal: &mut Data, ui: &mut Ui) {
+    if let Some(digits) = leading_zero_octal(&decimal.input_data) {
+        ui.horizontal(|ui| {
+            ui.colored_label(Color32::from_rgb(200, 120, 0), t("以0开头的数在C语言中是8进制，这里按10进制转换"));
+            if ui.small_button(t("按8进制转换")).on_hover_text(t("填入8进制输入框")).clicked() {
+                octal.input_data = digits;
+                octal.set_data_error(DataError::Nice);
+            }
+        });
+    }
+}
+
+//剪贴历史最多保留的条数
+const CLIPBOARD_LEN: usize = 10;
+
+fn clipboard_id() -> Id {
+    Id::new("剪贴历史")
+}
+
+//复制到剪贴板，同时记入剪贴历史，重复的记录移到最前
+fn copy_text(ui: &mut Ui, text: String) {
+    ui.data_mut(|d| {
+        let ring = d.get_temp_mut_or_default::<VecDeque<String>>(clipboard_id());
+        ring.retain(|copied| copied != &text);
+        ring.push_front(text.clone());
+        ring.truncate(CLIPBOARD_LEN);
+    });
+    ui.output_mut(|o| o.copied_text = text);
+}
+
+//剪贴历史下拉菜单，选中后重新复制到剪贴板，便于在面板之间搬运多个值
+pub fn clipboard_menu(ui: &mut Ui) {
+    let ring = ui.data(|d| d.get_temp::<VecDeque<String>>(clipboard_id())).unwrap_or_default();
+    ui.add_enabled_ui(!ring.is_empty(), |ui| {
+        ui.menu_button(t("剪贴历史"), |ui| {
+            for text in ring {
+                if ui.selectable_label(false, &text).clicked() {
+                    copy_text(ui, text);
+                    ui.close_menu();
+                }
+            }
+            ui.separator();
+            if ui.button(t("清空剪贴历史")).clicked() {
+                ui.data_mut(|d| d.remove::<VecDeque<String>>(clipboard_id()));
+                ui.close_menu();
+            }
+        });
+    });
+}
+
+//结果值后附带复制按钮，只复制该值本身
+pub fn copyable(ui: &mut Ui, text: &str) -> Response {
+    let response = ui.monospace(text);
+    if ui.small_button("📋").on_hover_text(t("复制")).clicked() {
+        copy_text(ui, text.to_string());
+    }
+    response
+}
+
+//2、8、16进制的结果，开启复制时带前缀后复制的内容加上0b、0o、0x，显示的内容不变
+pub fn radix_copyable(ui: &mut Ui, text: &str, radix: u32, settings: &Settings) -> Response {
+    let response = ui.monospace(text);
+    if ui.small_button("📋").on_hover_text(t("复制")).clicked() {
+        copy_text(ui, settings.copy_prefix(text, radix));
+    }
+    response
+}
+
+//2进制结果按设置分组，开启换行时每行4组显示，复制的仍是不换行的分组结果
+pub fn binary_output(ui: &mut Ui, digits: &str, settings: &Settings) -> Response {
+    let text = settings.group(digits, 2);
+    if !settings.wrap_binary {
+        return radix_copyable(ui, &text, 2, settings);
+    }
+    let response = ui.monospace(wrap_digits(digits, settings.grouping(2).0, 4));
+    if ui.small_button("📋").on_hover_text(t("复制")).clicked() {
+        copy_text(ui, settings.copy_prefix(&text, 2));
+    }
+    response
+}
+
+//输入框下方的只读分组预览
+pub fn group_preview(digits: &str, size: usize, ui: &mut Ui) {
+    if !digits.is_empty() {
+        ui.label(RichText::new(group_digits(digits, size)).monospace().color(Color32::GRAY));
+    }
+}
+
+//把数值当作字节数时的大小
+pub fn byte_size(value: u64, ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        ui.label(
+            RichText::new(t("作为字节数:{}").replace("{}", &format!("{} / {}", format_byte_size(value, true), format_byte_size(value, false))))
+                .color(Color32::GRAY),
+        );
+    });
+}
+
+//按设置的语言显示2、10、16进制的代码字面量
+pub fn code_literals(value: u64, settings: &Settings, ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(t("代码字面量:")).color(Color32::GRAY));
+        for radix in [16, 2, 10] {
+            copyable(ui, &code_literal(value, radix, settings.literal_language));
+        }
+    });
+}
+
+//按2的幂分解数值，如13 = 8 + 4 + 1 = 2^3 + 2^2 + 2^0
+pub fn bit_weight_breakdown(value: u64, ui: &mut Ui) {
+    CollapsingHeader::new(t("位权分解")).id_source("位权分解").show(ui, |ui| {
+        let weights = bit_weights(value);
+        if weights.is_empty() {
+            ui.monospace("0");
+            return;
+        }
+        let powers: Vec<String> = weights.iter().map(|bit| (1u64 << bit).to_string()).collect();
+        let exponents: Vec<String> = weights.iter().map(|bit| format!("2^{}", bit)).collect();
+        ui.monospace(format!("{} = {}", value, powers.join(" + ")));
+        ui.monospace(format!("  = {}", exponents.join(" + ")));
+    });
+}
+
+//f32的符号、指数和尾数分解，可折叠
+pub fn f32_breakdown(value: f32, settings: &Settings, ui: &mut Ui) {
+    CollapsingHeader::new(t("编码分解")).id_source("f32编码分解").show(ui, |ui| {
+        let parts = F32Parts::new(value);
+        let bits = format!("{:032b}", f32_bits(value));
+        ui.monospace(format!("{}: {} ({})", t("负号"), &bits[..1], if parts.sign { "-" } else { "+" }));
+        let exponent = match parts.unbiased_exponent() {
+            Some(exponent) => format!("2^{}", exponent),
+            None => "∞/NaN".to_string(),
+        };
+        ui.monospace(format!("{}: {} = {} - 127 → {}", t("指数"), &bits[1..9], parts.exponent, exponent));
+        ui.monospace(format!("{}: {} = 0x{}", t("尾数"), &bits[9..], settings.hex_case(format!("{:06x}", parts.mantissa))));
+        let category = match parts.category() {
+            FloatCategory::Zero => t("零"),
+            FloatCategory::Subnormal => t("非规格化数"),
+            FloatCategory::Normal => t("规格化数"),
+            FloatCategory::Infinity => t("无穷大"),
+            FloatCategory::Nan => "NaN",
+        };
+        ui.monospace(format!("{}: {}", t("类别"), category));
+    });
+}
+
+//输入长度和上限，接近上限时变橙色，达到或超出时变红
+pub fn length_indicator(len: usize, max: usize, ui: &mut Ui) {
+    let color = match length_status(len, max) {
+        LengthStatus::Normal => Color32::GRAY,
+        LengthStatus::Near => Color32::from_rgb(200, 120, 0),
+        LengthStatus::Full | LengthStatus::Over => Color32::RED,
+    };
+    ui.label(RichText::new(format!("{}/{}", len, max)).monospace().color(color));
+}
+
+//格式错误提示，附带输入中的无效字符
+//换行来自粘贴的多行数值，单独提示而不列为无效字符
+pub fn format_error(ui: &mut Ui, message: &str, invalid: &[char]) -> Response {
+    let multiline = invalid.iter().any(|c| ['\n', '\r'].contains(c));
+    let chars: Vec<String> = invalid.iter().filter(|c| !['\n', '\r'].contains(c)).map(|c| c.to_string()).collect();
+    let mut text = message.to_string();
+    if !chars.is_empty() {
+        text += &t("(无效字符:{})").replace("{}", &chars.join(", "));
+    }
+    if multiline {
+        text += t("(输入包含多行，可在智能输入中逐行转换)");
+    }
+    ui.colored_label(Color32::RED, text)
+}
+
+//浮点格式的10进制精度：至少能保留的有效数字和唯一还原所需的有效数字
+pub fn float_precision(double: bool, ui: &mut Ui) {
+    let (min, max) = if double { (f64::DIGITS, 17) } else { (f32::DIGITS, 9) };
+    ui.label(RichText::new(t("10进制精度:{}~{}位有效数字").replacen("{}", &min.to_string(), 1).replacen("{}", &max.to_string(), 1)).color(Color32::GRAY));
+}
+
+//数值超出设置的位宽被截断时提示
+pub fn overflow_note(overflowed: bool, settings: &Settings, ui: &mut Ui) {
+    if overflowed {
+        ui.colored_label(Color32::from_rgb(200, 120, 0), t("超出{}位，已截断").replace("{}", &settings.width_bits().to_string()));
+    }
+}
+
+//有效位数和字节数，input_bits为包含前导零的输入宽度
+pub fn bit_stats(value: u64, input_bits: Option<usize>, ui: &mut Ui) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(t("有效位数:{}").replace("{}", &significant_bits(value).to_string())).color(Color32::GRAY));
+        ui.label(RichText::new(t("字节数:{}").replace("{}", &significant_bytes(value).to_string())).color(Color32::GRAY));
+        if let Some(bits) = input_bits {
+            ui.label(RichText::new(t("输入宽度:{}位").replace("{}", &bits.to_string())).color(Color32::GRAY));
+        }
+    });
+}
+
+//不显示窗口，绘制一帧面板，供各面板的测试使用
+#[cfg(test)]
+pub fn run_panel(panel: fn(&mut Data, &Settings, &mut Ui), data: &mut Data, settings: &Settings) {
+    let ctx = Context::default();
+    let _ = ctx.run(Default::default(), |ctx| {
+        CentralPanel::default().show(ctx, |ui| show_panel(data, settings, ui, panel));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base16::base16;
+    use crate::hex_floats::hex_floats;
+
+    #[test]
+    fn highlight_ignores_separators() {
+        assert!(valid_chars("_0b1010", 2).iter().all(|&valid| valid));
+        assert!(valid_chars("0x_FF_u8", 16).iter().all(|&valid| valid));
+        assert_eq!(valid_chars("_0xFG", 16), [true, true, true, true, false]);
+    }
+
+    #[test]
+    fn last_output_survives_invalid_edit() {
+        let mut settings = Settings::new();
+        settings.keep_last_output = true;
+        let mut data = Data::new();
+        data.input_data = "ff".to_string();
+        run_panel(base16, &mut data, &settings);
+        assert_eq!(data.output_data, "0b11111111 255");
+
+        data.input_data = "fg".to_string();
+        run_panel(base16, &mut data, &settings);
+        assert!(data.get_data_error() == &DataError::FormatError);
+        assert_eq!(data.output_data, "0b11111111 255");
+    }
+
+    #[test]
+    fn partial_float_array_is_not_kept() {
+        let settings = Settings::new();
+        let mut data = Data::new();
+        data.input_data = "3F800000 40000000".to_string();
+        run_panel(hex_floats, &mut data, &settings);
+        assert_eq!(data.output_data, "1\n2");
+
+        //第一个字仍能转换，但整段输入无效
+        data.input_data = "3F800000 4000000G".to_string();
+        run_panel(hex_floats, &mut data, &settings);
+        assert!(data.get_data_error() == &DataError::FormatError);
+        assert_eq!(data.output_data, "1\n2");
+    }
+}